@@ -0,0 +1,305 @@
+use crate::{CryptoEntropy, DrbgError, Entropy, RandomBitGenerator, for_each_mechanism};
+use rand_core::{OsRng, TryCryptoRng, TryRngCore};
+
+// Operations forwarded by `AnyDrbg`, on top of the shared `RandomBitGenerator` ones.
+trait Instance<E: Entropy>: RandomBitGenerator<Error = DrbgError<E::Error>> {
+    fn fill_bytes_pr(
+        &mut self,
+        bytes: &mut [u8],
+        additional_input: &[u8],
+    ) -> Result<(), DrbgError<E::Error>>;
+    fn reseed_with_entropy_input(
+        &mut self,
+        entropy_input: &[u8],
+        additional_input: &[u8],
+    ) -> Result<(), DrbgError<E::Error>>;
+    fn is_approved(&self) -> bool;
+    fn is_in_error_state(&self) -> bool;
+    fn self_test(&mut self) -> Result<(), DrbgError<E::Error>>;
+}
+
+macro_rules! impl_instance {
+    ($name:ident, NoPr) => {
+        impl_instance!(@impl $name, |drbg, bytes, additional_input| drbg.fill_bytes_pr(bytes, additional_input));
+    };
+    // Pr variants already reseed on every request.
+    ($name:ident, Pr) => {
+        impl_instance!(@impl $name, |drbg, bytes, additional_input| drbg.fill_bytes_with_ai(bytes, additional_input));
+    };
+    (@impl $name:ident, |$drbg:ident, $bytes:ident, $additional_input:ident| $fill_bytes_pr:expr) => {
+        impl<E: Entropy> Instance<E> for crate::$name<E> {
+            fn fill_bytes_pr(
+                &mut self,
+                $bytes: &mut [u8],
+                $additional_input: &[u8],
+            ) -> Result<(), DrbgError<E::Error>> {
+                let $drbg = self;
+                $fill_bytes_pr
+            }
+
+            fn reseed_with_entropy_input(
+                &mut self,
+                entropy_input: &[u8],
+                additional_input: &[u8],
+            ) -> Result<(), DrbgError<E::Error>> {
+                crate::$name::reseed_with_entropy_input(self, entropy_input, additional_input)
+            }
+
+            fn is_approved(&self) -> bool {
+                crate::$name::is_approved(self)
+            }
+
+            fn is_in_error_state(&self) -> bool {
+                crate::$name::is_in_error_state(self)
+            }
+
+            fn self_test(&mut self) -> Result<(), DrbgError<E::Error>> {
+                crate::$name::self_test(self)
+            }
+        }
+    };
+}
+
+// Identifiers are compared without case, punctuation or a "DRBG" marker,
+// so "HMAC_DRBG SHA-256", "hmac-sha256" and "drbg_hmac_sha256" name the same mechanism.
+fn normalize(identifier: &str) -> String {
+    identifier
+        .to_ascii_lowercase()
+        .replace("drbg", "")
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .collect()
+}
+
+// Matches the mechanism name, the type name without the "Drbg" prefix,
+// and for CTR_DRBG the bare CAVP header (e.g. "[AES-256 use df]") since the cipher implies the mechanism.
+fn identifies(identifier: &str, mechanism: &str, short_name: &str) -> bool {
+    let mechanism = normalize(mechanism);
+    identifier == mechanism
+        || identifier == normalize(short_name)
+        || mechanism.strip_prefix("ctr") == Some(identifier)
+}
+
+macro_rules! define_any_drbg {
+    ($($(#[$attr:meta])* ($mechanism:ident, $pr_mechanism:ident, $name:ident, $builder:ident, $pr_name:ident, $pr_builder:ident, $variant:ident, $inner:ident)),*$(,)?) => {
+        /// Any of the DRBG types, selected at runtime from a mechanism identifier.
+        ///
+        /// # Usage
+        ///
+        /// ```ignore
+        /// let drbg = AnyDrbg::new("[AES-256 use df]", false)?;
+        /// let drbg = AnyDrbg::with_entropy("hmac-sha256", true, CustomEntropy)?;
+        /// ```
+        pub enum AnyDrbg<E = OsRng> {
+            $(
+                $(#[$attr])* $mechanism(crate::$name<E>),
+                $(#[$attr])* $pr_mechanism(crate::$pr_name<E>),
+            )*
+        }
+
+        $(
+            $(#[$attr])* impl_instance!($name, NoPr);
+            $(#[$attr])* impl_instance!($pr_name, Pr);
+        )*
+
+        impl AnyDrbg {
+            /// Names of the supported mechanisms, as returned by `RandomBitGenerator::mechanism`.
+            pub const MECHANISMS: &'static [&'static str] = &[
+                $($(#[$attr])* <crate::$name>::MECHANISM,)*
+            ];
+
+            /// Instantiate the identified mechanism with OsRng entropy, see `with_entropy`.
+            pub fn new(
+                mechanism: &str,
+                prediction_resistance: bool,
+            ) -> Result<Self, DrbgError<<OsRng as TryRngCore>::Error>> {
+                Self::with_entropy(mechanism, prediction_resistance, OsRng)
+            }
+        }
+
+        impl<E: Entropy> AnyDrbg<E> {
+            /// Instantiate the identified mechanism with no personalization string, generated nonce, and default reseed interval.
+            ///
+            /// Accepts the names listed in `MECHANISMS` (e.g. `"HMAC_DRBG SHA-256"`), the CTR_DRBG CAVP headers (e.g. `"[AES-256 use df]"`)
+            /// and short names (e.g. `"hmac-sha256"`, `"ctr-nodf-aes128"`), without regard to case or punctuation.
+            /// The CAVP Hash_DRBG and HMAC_DRBG headers (e.g. `"[SHA-256]"`) are ambiguous and need the mechanism (`"HMAC_DRBG [SHA-256]"`).
+            /// Unknown identifiers fail with `DrbgError::UnknownMechanism`.
+            pub fn with_entropy(
+                mechanism: &str,
+                prediction_resistance: bool,
+                entropy: E,
+            ) -> Result<Self, DrbgError<E::Error>> {
+                let identifier = normalize(mechanism);
+                $(
+                    $(#[$attr])*
+                    if identifies(&identifier, <crate::$name>::MECHANISM, stringify!($mechanism)) {
+                        return if prediction_resistance {
+                            crate::$pr_name::builder().entropy(entropy).build().map(Self::$pr_mechanism)
+                        } else {
+                            crate::$name::builder().entropy(entropy).build().map(Self::$mechanism)
+                        };
+                    }
+                )*
+                Err(DrbgError::UnknownMechanism)
+            }
+
+            fn instance(&self) -> &dyn Instance<E> {
+                match self {
+                    $(
+                        $(#[$attr])* Self::$mechanism(drbg) => drbg,
+                        $(#[$attr])* Self::$pr_mechanism(drbg) => drbg,
+                    )*
+                }
+            }
+
+            fn instance_mut(&mut self) -> &mut dyn Instance<E> {
+                match self {
+                    $(
+                        $(#[$attr])* Self::$mechanism(drbg) => drbg,
+                        $(#[$attr])* Self::$pr_mechanism(drbg) => drbg,
+                    )*
+                }
+            }
+
+            /// Uninstantiate the DRBG, zeroizing its internal state, and hand back the entropy source.
+            pub fn uninstantiate(self) -> E {
+                match self {
+                    $(
+                        $(#[$attr])* Self::$mechanism(drbg) => drbg.uninstantiate(),
+                        $(#[$attr])* Self::$pr_mechanism(drbg) => drbg.uninstantiate(),
+                    )*
+                }
+            }
+        }
+    };
+}
+
+impl<E: Entropy> AnyDrbg<E> {
+    /// Fill bytes array with random bits.
+    pub fn fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), DrbgError<E::Error>> {
+        self.instance_mut().fill_bytes(bytes)
+    }
+
+    /// Fill bytes array with random bits, `additional_input` will be factored into the bit generation.
+    pub fn fill_bytes_with_ai(
+        &mut self,
+        bytes: &mut [u8],
+        additional_input: &[u8],
+    ) -> Result<(), DrbgError<E::Error>> {
+        self.instance_mut()
+            .fill_bytes_with_ai(bytes, additional_input)
+    }
+
+    /// Fill bytes array with random bits, reseeding from the entropy source first.
+    ///
+    /// Same as `fill_bytes_with_ai` for prediction resistant DRBGs, which already reseed on every request.
+    pub fn fill_bytes_pr(
+        &mut self,
+        bytes: &mut [u8],
+        additional_input: &[u8],
+    ) -> Result<(), DrbgError<E::Error>> {
+        self.instance_mut().fill_bytes_pr(bytes, additional_input)
+    }
+
+    /// Reseed the DRBG from its entropy source.
+    pub fn reseed(&mut self) -> Result<(), DrbgError<E::Error>> {
+        self.instance_mut().reseed()
+    }
+
+    /// Reseed the DRBG from its entropy source, `additional_input` will be factored into the new state.
+    pub fn reseed_with_ai(&mut self, additional_input: &[u8]) -> Result<(), DrbgError<E::Error>> {
+        self.instance_mut().reseed_with_ai(additional_input)
+    }
+
+    /// Reseed the DRBG from caller-supplied entropy input instead of reading it from the entropy source.
+    pub fn reseed_with_entropy_input(
+        &mut self,
+        entropy_input: &[u8],
+        additional_input: &[u8],
+    ) -> Result<(), DrbgError<E::Error>> {
+        self.instance_mut()
+            .reseed_with_entropy_input(entropy_input, additional_input)
+    }
+
+    /// Whether this DRBG runs in an approved (FIPS) configuration.
+    pub fn is_approved(&self) -> bool {
+        self.instance().is_approved()
+    }
+
+    /// Whether the DRBG refuses requests because it entered the error state.
+    pub fn is_in_error_state(&self) -> bool {
+        self.instance().is_in_error_state()
+    }
+
+    /// Run the mechanism's known-answer self-test on demand.
+    pub fn self_test(&mut self) -> Result<(), DrbgError<E::Error>> {
+        self.instance_mut().self_test()
+    }
+
+    /// Security strength in bits this DRBG was instantiated at.
+    pub fn security_strength(&self) -> usize {
+        self.instance().security_strength()
+    }
+
+    /// Mechanism name, see `RandomBitGenerator::mechanism`.
+    pub fn mechanism(&self) -> &'static str {
+        self.instance().mechanism()
+    }
+
+    /// Whether every request reseeds from the entropy source.
+    pub fn prediction_resistance(&self) -> bool {
+        self.instance().prediction_resistance()
+    }
+}
+
+impl<E: Entropy> RandomBitGenerator for AnyDrbg<E> {
+    type Error = DrbgError<E::Error>;
+
+    fn fill_bytes_with_ai(
+        &mut self,
+        bytes: &mut [u8],
+        additional_input: &[u8],
+    ) -> Result<(), Self::Error> {
+        AnyDrbg::fill_bytes_with_ai(self, bytes, additional_input)
+    }
+
+    fn reseed_with_ai(&mut self, additional_input: &[u8]) -> Result<(), Self::Error> {
+        AnyDrbg::reseed_with_ai(self, additional_input)
+    }
+
+    fn security_strength(&self) -> usize {
+        AnyDrbg::security_strength(self)
+    }
+
+    fn mechanism(&self) -> &'static str {
+        AnyDrbg::mechanism(self)
+    }
+
+    fn prediction_resistance(&self) -> bool {
+        AnyDrbg::prediction_resistance(self)
+    }
+}
+
+impl<E: Entropy> TryRngCore for AnyDrbg<E> {
+    type Error = DrbgError<E::Error>;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        let mut bytes = [0; std::mem::size_of::<u32>()];
+        self.try_fill_bytes(&mut bytes)?;
+        Ok(u32::from_ne_bytes(bytes))
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        let mut bytes = [0; std::mem::size_of::<u64>()];
+        self.try_fill_bytes(&mut bytes)?;
+        Ok(u64::from_ne_bytes(bytes))
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        self.fill_bytes(dst)
+    }
+}
+
+impl<E: CryptoEntropy> TryCryptoRng for AnyDrbg<E> {}
+
+for_each_mechanism!(define_any_drbg);
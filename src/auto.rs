@@ -0,0 +1,207 @@
+use crate::{
+    AnyDrbg, CryptoEntropy, DrbgCtrAes256, DrbgError, DrbgHmacSha512, DrbgPrCtrAes256,
+    DrbgPrHmacSha512, DrbgVariant, Entropy, RandomBitGenerator,
+};
+use rand_core::{OsRng, TryCryptoRng, TryRngCore};
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+cpufeatures::new!(aes_intrinsics, "aes", "sse2");
+#[cfg(target_arch = "aarch64")]
+cpufeatures::new!(aes_intrinsics, "aes");
+
+// Both mechanisms have to offer the same security strength, whichever one is picked.
+const _: () = assert!(
+    <crate::Ctr<crate::Aes256> as DrbgVariant>::SECURITY_STRENGTH
+        == <crate::Hmac<sha2::Sha512> as DrbgVariant>::SECURITY_STRENGTH
+);
+
+/// DRBG that picks its mechanism from the CPU it runs on.
+///
+/// Uses CTR_DRBG AES-256 when AES instructions are available, and HMAC_DRBG SHA-512 otherwise,
+/// which avoids table-based software AES. Both give a 256 bit security strength.
+///
+/// # Usage
+///
+/// ```ignore
+/// let drbg = AutoDrbg::new(false)?;
+/// println!("Using {}", drbg.mechanism());
+/// ```
+pub struct AutoDrbg<E = OsRng>(AnyDrbg<E>);
+
+impl AutoDrbg {
+    /// Instantiate the selected mechanism with OsRng entropy, see `with_entropy`.
+    pub fn new(
+        prediction_resistance: bool,
+    ) -> Result<Self, DrbgError<<OsRng as TryRngCore>::Error>> {
+        Self::with_entropy(prediction_resistance, OsRng)
+    }
+
+    /// Whether the CPU has AES instructions, detected once per process.
+    pub fn aes_available() -> bool {
+        #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+        return aes_intrinsics::get();
+        #[cfg(not(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")))]
+        return false;
+    }
+
+    /// Mechanism name `with_entropy` instantiates on this CPU, see `RandomBitGenerator::mechanism`.
+    pub fn select() -> &'static str {
+        if Self::aes_available() {
+            <DrbgCtrAes256>::MECHANISM
+        } else {
+            <DrbgHmacSha512>::MECHANISM
+        }
+    }
+}
+
+impl<E: Entropy> AutoDrbg<E> {
+    /// Instantiate the selected mechanism with no personalization string, generated nonce, and default reseed interval.
+    pub fn with_entropy(
+        prediction_resistance: bool,
+        entropy: E,
+    ) -> Result<Self, DrbgError<E::Error>> {
+        let drbg = match (AutoDrbg::aes_available(), prediction_resistance) {
+            (true, false) => DrbgCtrAes256::builder()
+                .entropy(entropy)
+                .build()
+                .map(AnyDrbg::CtrAes256),
+            (true, true) => DrbgPrCtrAes256::builder()
+                .entropy(entropy)
+                .build()
+                .map(AnyDrbg::PrCtrAes256),
+            (false, false) => DrbgHmacSha512::builder()
+                .entropy(entropy)
+                .build()
+                .map(AnyDrbg::HmacSha512),
+            (false, true) => DrbgPrHmacSha512::builder()
+                .entropy(entropy)
+                .build()
+                .map(AnyDrbg::PrHmacSha512),
+        };
+        drbg.map(Self)
+    }
+
+    /// Fill bytes array with random bits.
+    pub fn fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), DrbgError<E::Error>> {
+        self.0.fill_bytes(bytes)
+    }
+
+    /// Fill bytes array with random bits, `additional_input` will be factored into the bit generation.
+    pub fn fill_bytes_with_ai(
+        &mut self,
+        bytes: &mut [u8],
+        additional_input: &[u8],
+    ) -> Result<(), DrbgError<E::Error>> {
+        self.0.fill_bytes_with_ai(bytes, additional_input)
+    }
+
+    /// Fill bytes array with random bits, reseeding from the entropy source first.
+    pub fn fill_bytes_pr(
+        &mut self,
+        bytes: &mut [u8],
+        additional_input: &[u8],
+    ) -> Result<(), DrbgError<E::Error>> {
+        self.0.fill_bytes_pr(bytes, additional_input)
+    }
+
+    /// Reseed the DRBG from its entropy source.
+    pub fn reseed(&mut self) -> Result<(), DrbgError<E::Error>> {
+        self.0.reseed()
+    }
+
+    /// Reseed the DRBG from its entropy source, `additional_input` will be factored into the new state.
+    pub fn reseed_with_ai(&mut self, additional_input: &[u8]) -> Result<(), DrbgError<E::Error>> {
+        self.0.reseed_with_ai(additional_input)
+    }
+
+    /// Reseed the DRBG from caller-supplied entropy input instead of reading it from the entropy source.
+    pub fn reseed_with_entropy_input(
+        &mut self,
+        entropy_input: &[u8],
+        additional_input: &[u8],
+    ) -> Result<(), DrbgError<E::Error>> {
+        self.0
+            .reseed_with_entropy_input(entropy_input, additional_input)
+    }
+
+    /// Whether this DRBG runs in an approved (FIPS) configuration.
+    pub fn is_approved(&self) -> bool {
+        self.0.is_approved()
+    }
+
+    /// Whether the DRBG refuses requests because it entered the error state.
+    pub fn is_in_error_state(&self) -> bool {
+        self.0.is_in_error_state()
+    }
+
+    /// Run the mechanism's known-answer self-test on demand.
+    pub fn self_test(&mut self) -> Result<(), DrbgError<E::Error>> {
+        self.0.self_test()
+    }
+
+    /// Uninstantiate the DRBG, zeroizing its internal state, and hand back the entropy source.
+    pub fn uninstantiate(self) -> E {
+        self.0.uninstantiate()
+    }
+
+    /// Security strength in bits this DRBG was instantiated at.
+    pub fn security_strength(&self) -> usize {
+        self.0.security_strength()
+    }
+
+    /// Mechanism this DRBG selected, see `RandomBitGenerator::mechanism`.
+    pub fn mechanism(&self) -> &'static str {
+        self.0.mechanism()
+    }
+
+    /// Whether every request reseeds from the entropy source.
+    pub fn prediction_resistance(&self) -> bool {
+        self.0.prediction_resistance()
+    }
+}
+
+impl<E: Entropy> RandomBitGenerator for AutoDrbg<E> {
+    type Error = DrbgError<E::Error>;
+
+    fn fill_bytes_with_ai(
+        &mut self,
+        bytes: &mut [u8],
+        additional_input: &[u8],
+    ) -> Result<(), Self::Error> {
+        AutoDrbg::fill_bytes_with_ai(self, bytes, additional_input)
+    }
+
+    fn reseed_with_ai(&mut self, additional_input: &[u8]) -> Result<(), Self::Error> {
+        AutoDrbg::reseed_with_ai(self, additional_input)
+    }
+
+    fn security_strength(&self) -> usize {
+        AutoDrbg::security_strength(self)
+    }
+
+    fn mechanism(&self) -> &'static str {
+        AutoDrbg::mechanism(self)
+    }
+
+    fn prediction_resistance(&self) -> bool {
+        AutoDrbg::prediction_resistance(self)
+    }
+}
+
+impl<E: Entropy> TryRngCore for AutoDrbg<E> {
+    type Error = DrbgError<E::Error>;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        self.0.try_next_u32()
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        self.0.try_next_u64()
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        self.0.try_fill_bytes(dst)
+    }
+}
+
+impl<E: CryptoEntropy> TryCryptoRng for AutoDrbg<E> {}
@@ -1,18 +1,37 @@
+use crate::drbg::{self_test::hex, variant::Approval};
 use aes::cipher::{
     consts::{U32, U40, U48},
     generic_array::GenericArray,
 };
 
+/// Block cipher used by CTR_DRBG (Section 10.2.1 Table 3).
+///
+/// Lengths and the security strength are in bytes, keys can be at most 32 bytes long for the derivation function.
+/// Implement it to instantiate `Ctr<MyCipher>` and `CtrNoDf<MyCipher>`.
 pub trait Cipher {
+    /// Block cipher name as in the CAVP response files, e.g. `"AES-256"`.
+    const NAME: &'static str;
+    /// blocklen
     const BLOCK_LEN: usize;
+    /// keylen
     const KEY_LEN: usize;
+    /// seedlen
     const SEED_LEN: usize = Self::BLOCK_LEN + Self::KEY_LEN;
 
     const SECURITY_STRENGTH: usize;
     const MAX_RESEED_INTERVAL: u64;
+    const MAX_BYTES_PER_REQUEST: usize;
+    /// Whether the block cipher is approved for CTR_DRBG (SP 800-131A).
+    ///
+    /// Only this crate's AES types are approved, it cannot be set by other crates.
+    const APPROVED: Approval = Approval(false);
+
+    /// Known answers of the CTR_DRBG self-test with and without df, see `DrbgVariant::SELF_TEST_KAT`.
+    const DF_KAT: Option<[u8; 32]> = None;
+    const NO_DF_KAT: Option<[u8; 32]> = None;
 
     type Block: AsRef<[u8]> + AsMut<[u8]>;
-    type Key: AsRef<[u8]>;
+    type Key: AsRef<[u8]> + AsMut<[u8]>;
     fn block_from_slice(slice: &[u8]) -> Self::Block;
     fn key_from_slice(slice: &[u8]) -> Self::Key;
 
@@ -31,16 +50,23 @@ pub trait Cipher {
     fn block_encrypt_b2b(&self, block: &Self::Block) -> Self::Block;
 }
 
-macro_rules! impl_aes {
-    ($cipher:ident, $inner:ident, $block_len:literal, $key_len:literal, $seed_len:ident, $nonce_len:ident) => {
+// Block ciphers with a 128 bit block from RustCrypto crates.
+macro_rules! impl_block_cipher {
+    ($cipher:ident, $inner:path, $name:literal, $key_len:literal, $seed_len:ident, $approved:literal, $df_kat:literal, $no_df_kat:literal) => {
         pub struct $cipher($inner);
 
         impl Cipher for $cipher {
-            const BLOCK_LEN: usize = $block_len;
+            const NAME: &'static str = $name;
+            const BLOCK_LEN: usize = 16;
             const KEY_LEN: usize = $key_len;
 
             const SECURITY_STRENGTH: usize = Self::KEY_LEN;
             const MAX_RESEED_INTERVAL: u64 = 1 << 48;
+            const MAX_BYTES_PER_REQUEST: usize = 1 << 16;
+            const APPROVED: Approval = Approval($approved);
+
+            const DF_KAT: Option<[u8; 32]> = Some(hex($df_kat));
+            const NO_DF_KAT: Option<[u8; 32]> = Some(hex($no_df_kat));
 
             type Block = aes::Block;
             type Key = aes::cipher::Key<$inner>;
@@ -58,7 +84,7 @@ macro_rules! impl_aes {
 
             fn new(key: &Self::Key) -> Self {
                 use aes::cipher::KeyInit;
-                Self($inner::new(key))
+                Self(<$inner>::new(key))
             }
             fn block_encrypt(&self, block: &mut Self::Block) {
                 use aes::cipher::BlockEncrypt;
@@ -75,6 +101,136 @@ macro_rules! impl_aes {
 }
 
 use aes::{Aes128Enc, Aes192Enc, Aes256Enc};
-impl_aes!(Aes256, Aes256Enc, 16, 32, U48, U16);
-impl_aes!(Aes192, Aes192Enc, 16, 24, U40, U12);
-impl_aes!(Aes128, Aes128Enc, 16, 16, U32, U8);
+impl_block_cipher!(
+    Aes256,
+    Aes256Enc,
+    "AES-256",
+    32,
+    U48,
+    true,
+    "50a0376f0f2a6404cf98d945f64a12e14a748e430c981ddead64f2bc961cf066",
+    "9387112c50f660f410ec89a617b778ee8f6b9f3437d96ca828e82508568c1686"
+);
+impl_block_cipher!(
+    Aes192,
+    Aes192Enc,
+    "AES-192",
+    24,
+    U40,
+    true,
+    "3b1ad88410365422dfe35425c5b9428eacd67ce6565c85ffd5e9ea20cac0fce2",
+    "080303e301647d5607b8c038a92bc49b99ebeb080ed5750f5e3d7d379f85ef1f"
+);
+impl_block_cipher!(
+    Aes128,
+    Aes128Enc,
+    "AES-128",
+    16,
+    U32,
+    true,
+    "762e32002c65e5fe7bae92e34b67b205e9293b52b97ec8b8a93a9b355235ae4b",
+    "5c52f8c9dabfeb16c5dbee146c73d8b1ac21d99d8a5be21529f69db4ad8bc7d7"
+);
+
+// Camellia is not approved for CTR_DRBG by SP 800-90A, it is provided for deployments that require it (e.g. CRYPTREC).
+#[cfg(feature = "camellia")]
+impl_block_cipher!(
+    Camellia256,
+    camellia::Camellia256,
+    "Camellia-256",
+    32,
+    U48,
+    false,
+    "243ccc6473361af26a738b17bc8bfbba717b3f42a2ab330c45e716c837ed2020",
+    "31d5db537a46a9ab029acca5cf711618b8a8ac9daf5bf6b447eb297a2ba5a129"
+);
+#[cfg(feature = "camellia")]
+impl_block_cipher!(
+    Camellia192,
+    camellia::Camellia192,
+    "Camellia-192",
+    24,
+    U40,
+    false,
+    "4719dde39994085bd51e54aa638f8510460f828818654d34c144fdb15c718742",
+    "2af7c909420cefd7376f765055ac6980f1d197c793cf2a5c205ce33e82993959"
+);
+#[cfg(feature = "camellia")]
+impl_block_cipher!(
+    Camellia128,
+    camellia::Camellia128,
+    "Camellia-128",
+    16,
+    U32,
+    false,
+    "4081ebb083a63f52a8067a746cdf456b11658c24c36841419dc5ea5182a3247b",
+    "f0b104e0dd86246e0eef85da1f48c454db5e2af0339645254b56f5529b941953"
+);
+
+#[cfg(feature = "legacy-tdea")]
+pub struct Tdea3(des::TdesEde3);
+
+#[cfg(feature = "legacy-tdea")]
+impl Tdea3 {
+    // Section 10.2.1 Table 3 uses a 168 bit key, the parity bits are added back here before keying the cipher.
+    fn expand_key(key: &[u8]) -> [u8; 24] {
+        let mut expanded = [0; 24];
+        for (i, byte) in expanded.iter_mut().enumerate() {
+            for bit in 0..7 {
+                let pos = i * 7 + bit;
+                if key[pos / 8] & (0x80 >> (pos % 8)) != 0 {
+                    *byte |= 0x80 >> bit;
+                }
+            }
+        }
+        expanded
+    }
+}
+
+#[cfg(feature = "legacy-tdea")]
+impl Cipher for Tdea3 {
+    const NAME: &'static str = "3KeyTDEA";
+    const BLOCK_LEN: usize = 8;
+    const KEY_LEN: usize = 21;
+
+    const SECURITY_STRENGTH: usize = 14;
+    const MAX_RESEED_INTERVAL: u64 = 1 << 32;
+    const MAX_BYTES_PER_REQUEST: usize = 1 << 10;
+    // TDEA is disallowed for random bit generation after 2023 (SP 800-131A).
+
+    const DF_KAT: Option<[u8; 32]> = Some(hex(
+        "b256f3546a4a6e354b199b5675abcd7e609c69d3932f3e0af14140bcb3d13a04",
+    ));
+    const NO_DF_KAT: Option<[u8; 32]> = Some(hex(
+        "9959126e0b03468323f989f43abf13c4de8a39029d6846efbc7c0ef00f165943",
+    ));
+
+    type Block = des::cipher::Block<des::TdesEde3>;
+    type Key = GenericArray<u8, des::cipher::consts::U21>;
+    fn block_from_slice(slice: &[u8]) -> Self::Block {
+        Self::Block::clone_from_slice(slice)
+    }
+    fn key_from_slice(slice: &[u8]) -> Self::Key {
+        Self::Key::clone_from_slice(slice)
+    }
+
+    type Seed = GenericArray<u8, des::cipher::consts::U29>;
+    fn seed_from_slice(slice: &[u8]) -> Self::Seed {
+        Self::Seed::clone_from_slice(slice)
+    }
+
+    fn new(key: &Self::Key) -> Self {
+        use des::cipher::KeyInit;
+        Self(des::TdesEde3::new(&Self::expand_key(key).into()))
+    }
+    fn block_encrypt(&self, block: &mut Self::Block) {
+        use des::cipher::BlockEncrypt;
+        self.0.encrypt_block(block);
+    }
+    fn block_encrypt_b2b(&self, block: &Self::Block) -> Self::Block {
+        use des::cipher::BlockEncrypt;
+        let mut out_block = Self::Block::default();
+        self.0.encrypt_block_b2b(block, &mut out_block);
+        out_block
+    }
+}
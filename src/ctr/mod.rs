@@ -1,24 +1,57 @@
-use crate::drbg::variant::DrbgVariant;
-use cipher::Cipher;
+use crate::drbg::{
+    DrbgBuilder,
+    variant::{Approval, DrbgVariant, MechanismName},
+};
+use zeroize::Zeroize;
 
 mod cipher;
 mod util;
 
-pub use cipher::{Aes128, Aes192, Aes256};
+#[cfg(feature = "legacy-tdea")]
+pub use cipher::Tdea3;
+pub use cipher::{Aes128, Aes192, Aes256, Cipher};
+#[cfg(feature = "camellia")]
+pub use cipher::{Camellia128, Camellia192, Camellia256};
 
-pub struct Ctr<C: Cipher> {
+/// CTR_DRBG. `DF` selects whether seed material is conditioned by the block cipher derivation function.
+pub struct Ctr<C: Cipher, const DF: bool = true> {
     v: C::Block,
     key: C::Key,
+    ctr_len: usize,
 }
 
-impl<C: Cipher> Ctr<C> {
+/// CTR_DRBG instantiation parameters.
+#[derive(Clone, Copy, Default)]
+pub struct CtrParams {
+    // Number of rightmost bits of V incremented as the counter. Defaults to blocklen.
+    pub(crate) ctr_len: Option<usize>,
+}
+
+/// CTR_DRBG without a derivation function. Entropy input must be full entropy.
+pub type CtrNoDf<C> = Ctr<C, false>;
+
+impl<'a, Pr, C: Cipher, const DF: bool, E> DrbgBuilder<'a, Pr, Ctr<C, DF>, E> {
+    /// Number of rightmost bits of V used as the counter (Section 10.2.1).
+    ///
+    /// Must satisfy 4 <= ctr_len <= blocklen, defaults to blocklen.
+    /// Counters shorter than blocklen also reduce the maximum number of bytes per request.
+    pub fn ctr_len(mut self, ctr_len: usize) -> Self {
+        self.params.ctr_len = Some(ctr_len);
+        self
+    }
+}
+
+impl<C: Cipher, const DF: bool> Ctr<C, DF> {
+    const MECHANISM_NAME: MechanismName =
+        MechanismName::join(&["CTR_DRBG ", C::NAME, if DF { " use df" } else { " no df" }]);
+
     // Section 10.2.1.2
     fn update(&mut self, provided_data: &C::Seed) {
         let cipher = C::new(&self.key);
 
         let mut temp = C::seed_from_slice(&vec![0; C::SEED_LEN]);
         for block in temp.as_mut().chunks_mut(C::BLOCK_LEN) {
-            util::inc(self.v.as_mut());
+            util::inc(self.v.as_mut(), self.ctr_len);
             let output_block = cipher.block_encrypt_b2b(&self.v);
             block.copy_from_slice(&output_block.as_ref()[..block.len()])
         }
@@ -29,20 +62,67 @@ impl<C: Cipher> Ctr<C> {
 
         (self.key, self.v) = C::seed_to_key_block(temp);
     }
+
+    // Sections 10.2.1.3.1, 10.2.1.4.1 and 10.2.1.5.1
+    // Without df, the input is padded with zeros to seedlen and xored into the seed material.
+    fn xor_padded(seed_material: &mut C::Seed, input: &[u8]) {
+        for (byte, input_byte) in seed_material.as_mut().iter_mut().zip(input) {
+            *byte ^= input_byte;
+        }
+    }
 }
 
-impl<C: Cipher> DrbgVariant for Ctr<C> {
+impl<C: Cipher, const DF: bool> DrbgVariant for Ctr<C, DF> {
+    const NAME: &'static str = Self::MECHANISM_NAME.as_str();
     const MAX_RESEED_INTERVAL: u64 = C::MAX_RESEED_INTERVAL;
     const SECURITY_STRENGTH: usize = C::SECURITY_STRENGTH;
+    const MAX_BYTES_PER_REQUEST: usize = C::MAX_BYTES_PER_REQUEST;
+    const OUTPUT_BLOCK_LEN: usize = C::BLOCK_LEN;
+    const APPROVED: Approval = C::APPROVED;
+    const REQUIRES_FULL_ENTROPY: bool = !DF;
+
+    // Section 10.2.1 Table 3
+    // Without df, entropy input must be exactly seedlen bits of full entropy and the other inputs cannot exceed seedlen.
+    const MAX_ENTROPY: usize = if DF { 1 << 32 } else { C::SEED_LEN };
+    const MAX_PERSONALIZATION_STRING_LENGTH: usize = if DF { 1 << 32 } else { C::SEED_LEN };
+    const MAX_ADDITIONAL_INPUT_LENGTH: usize = if DF { 1 << 32 } else { C::SEED_LEN };
+    const USES_NONCE: bool = DF;
+
+    type Params = CtrParams;
+
+    // Section 10.2.1
+    // 4 <= ctr_len <= blocklen
+    fn valid_params(params: &Self::Params) -> bool {
+        params
+            .ctr_len
+            .is_none_or(|ctr_len| (4..=C::BLOCK_LEN * 8).contains(&ctr_len))
+    }
 
-    // Section 10.2.1.3.2
-    fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Self {
-        let seed_material = [entropy_input, nonce, personalization_string].concat();
-        let seed_material = util::block_cipher_df::<C>(&seed_material);
+    // Without df, entropy input is always seedlen bits regardless of the instantiated strength.
+    fn min_entropy(security_strength: usize) -> usize {
+        if DF { security_strength } else { C::SEED_LEN }
+    }
+
+    // Sections 10.2.1.3.1 and 10.2.1.3.2
+    fn instantiate(
+        params: &Self::Params,
+        entropy_input: &[u8],
+        nonce: &[u8],
+        personalization_string: &[u8],
+    ) -> Self {
+        let seed_material = if DF {
+            let seed_material = [entropy_input, nonce, personalization_string].concat();
+            util::block_cipher_df::<C>(&seed_material)
+        } else {
+            let mut seed_material = C::seed_from_slice(entropy_input);
+            Self::xor_padded(&mut seed_material, personalization_string);
+            seed_material
+        };
 
         let mut ctr = Self {
             v: C::block_from_slice(&vec![0; C::BLOCK_LEN]),
             key: C::key_from_slice(&vec![0; C::KEY_LEN]),
+            ctr_len: params.ctr_len.unwrap_or(C::BLOCK_LEN * 8),
         };
 
         ctr.update(&seed_material);
@@ -50,19 +130,31 @@ impl<C: Cipher> DrbgVariant for Ctr<C> {
         ctr
     }
 
-    // Section 10.2.1.4.2
+    // Sections 10.2.1.4.1 and 10.2.1.4.2
     fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) {
-        let seed_material = [entropy_input, additional_input].concat();
-        let seed_material = util::block_cipher_df::<C>(&seed_material);
+        let seed_material = if DF {
+            let seed_material = [entropy_input, additional_input].concat();
+            util::block_cipher_df::<C>(&seed_material)
+        } else {
+            let mut seed_material = C::seed_from_slice(entropy_input);
+            Self::xor_padded(&mut seed_material, additional_input);
+            seed_material
+        };
         self.update(&seed_material);
     }
 
-    // Section 10.2.1.5.2
+    // Sections 10.2.1.5.1 and 10.2.1.5.2
     fn generate(&mut self, bytes: &mut [u8], additional_input: &[u8], _: u64) {
         let additional_input = match additional_input.len() {
             0 => C::seed_from_slice(&vec![0; C::SEED_LEN]),
             _ => {
-                let additional_input = util::block_cipher_df::<C>(additional_input);
+                let additional_input = if DF {
+                    util::block_cipher_df::<C>(additional_input)
+                } else {
+                    let mut padded = C::seed_from_slice(&vec![0; C::SEED_LEN]);
+                    Self::xor_padded(&mut padded, additional_input);
+                    padded
+                };
                 self.update(&additional_input);
                 additional_input
             }
@@ -71,11 +163,32 @@ impl<C: Cipher> DrbgVariant for Ctr<C> {
         let cipher = C::new(&self.key);
 
         for block in bytes.chunks_mut(C::BLOCK_LEN) {
-            util::inc(self.v.as_mut());
+            util::inc(self.v.as_mut(), self.ctr_len);
             let output_block = cipher.block_encrypt_b2b(&self.v);
             block.copy_from_slice(&output_block.as_ref()[..block.len()]);
         }
 
         self.update(&additional_input);
     }
+
+    fn zeroize(&mut self) {
+        self.v.as_mut().zeroize();
+        self.key.as_mut().zeroize();
+    }
+
+    fn is_zeroized(&self) -> bool {
+        [self.v.as_ref(), self.key.as_ref()]
+            .concat()
+            .iter()
+            .all(|&byte| byte == 0)
+    }
+
+    const SELF_TEST_KAT: Option<[u8; 32]> = if DF { C::DF_KAT } else { C::NO_DF_KAT };
+
+    // Section 10.2.1 Table 3
+    // max_number_of_bits_per_request = min(B, 2^19) where B = (2^ctr_len - 4) * blocklen.
+    fn max_bytes_per_request(&self) -> usize {
+        let b = ((1u128 << self.ctr_len.min(64)) - 4) * C::BLOCK_LEN as u128;
+        b.min(C::MAX_BYTES_PER_REQUEST as u128) as usize
+    }
 }
@@ -1,11 +1,19 @@
 use crate::ctr::Cipher;
 
-pub fn inc(block: &mut [u8]) {
+// Increments the rightmost ctr_len bits of block modulo 2^ctr_len, leaving the leftmost bits untouched.
+pub fn inc(block: &mut [u8], ctr_len: usize) {
+    let mut remaining = ctr_len;
     for byte in block.iter_mut().rev() {
-        *byte = byte.wrapping_add(1);
-        if *byte != 0 {
+        if remaining == 0 {
             break;
         }
+        let mask = (0xffu16 >> (8 - remaining.min(8))) as u8;
+        let counter = (*byte & mask).wrapping_add(1) & mask;
+        *byte = (*byte & !mask) | counter;
+        if counter != 0 {
+            break;
+        }
+        remaining -= remaining.min(8);
     }
 }
 
@@ -35,7 +43,7 @@ pub fn block_cipher_df<C: Cipher>(input_string: &[u8]) -> C::Seed {
     for block in temp.as_mut().chunks_mut(C::BLOCK_LEN) {
         let chain = bcc::<C>(&k, &s);
         block.copy_from_slice(&chain.as_ref()[..block.len()]);
-        inc(&mut s[..std::mem::size_of::<u32>()]); // Increment IV
+        inc(&mut s[..std::mem::size_of::<u32>()], u32::BITS as usize); // Increment IV
     }
 
     let (k, mut x) = C::seed_to_key_block(temp);
@@ -0,0 +1,333 @@
+use crate::{
+    Entropy,
+    drbg::{
+        Drbg, DrbgError, ErrorPolicy, RequestMode, entropy_bytes, entropy_source_supports,
+        fill_entropy, instantiation_security_strength, is_approved, self_test,
+        variant::DrbgVariant,
+    },
+    pr::{NoPr, PredictionResistance},
+};
+use rand_core::OsRng;
+use std::{marker::PhantomData, time::Duration};
+use zeroize::Zeroizing;
+
+/// Builder for any `Drbg`, including ones over third-party `DrbgVariant`s.
+///
+/// The generated builders (e.g. `DrbgCtrAes256Builder`) wrap this builder for their mechanism.
+pub struct DrbgBuilder<'a, Pr, V: DrbgVariant, E> {
+    personalization_string: &'a [u8],
+    reseed_interval: Option<u64>,
+    nonce: Option<&'a [u8]>,
+    security_strength: Option<usize>,
+    entropy_input_len: Option<usize>,
+    entropy_timeout: Duration,
+    request_mode: RequestMode,
+    error_policy: ErrorPolicy,
+    self_test: bool,
+    continuous_test: bool,
+    pub(crate) params: V::Params,
+    entropy: E,
+    _pr: PhantomData<Pr>,
+}
+
+impl<Pr: PredictionResistance, V: DrbgVariant> Drbg<Pr, V, OsRng> {
+    /// Builder with OsRng entropy, no personalization string, generated nonce, and default reseed interval.
+    pub fn builder<'a>() -> DrbgBuilder<'a, Pr, V, OsRng> {
+        DrbgBuilder {
+            personalization_string: &[],
+            reseed_interval: None,
+            nonce: None,
+            security_strength: None,
+            entropy_input_len: None,
+            entropy_timeout: Duration::ZERO,
+            request_mode: RequestMode::default(),
+            error_policy: ErrorPolicy::default(),
+            self_test: false,
+            continuous_test: false,
+            params: Default::default(),
+            entropy: OsRng,
+            _pr: PhantomData,
+        }
+    }
+}
+
+impl<'a, Pr, V: DrbgVariant, E> DrbgBuilder<'a, Pr, V, E> {
+    pub fn personalization_string(mut self, personalization_string: &'a [u8]) -> Self {
+        self.personalization_string = personalization_string;
+        self
+    }
+
+    pub fn nonce(mut self, nonce: &'a [u8]) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Requested instantiation security strength in bits (Section 9.1).
+    ///
+    /// Rounded up to the nearest of 112, 128, 192 or 256 bits and cannot exceed the mechanism's security strength.
+    /// A lower security strength reduces the amount of entropy and nonce gathered by the DRBG.
+    pub fn security_strength(mut self, security_strength: usize) -> Self {
+        self.security_strength = Some(security_strength);
+        self
+    }
+
+    /// Number of entropy input bytes gathered on instantiation and every reseed.
+    ///
+    /// Defaults to the minimum entropy for the security strength (seedlen for CTR DRBGs without df),
+    /// over-sampled by the source's `Entropy::MIN_ENTROPY_PER_BIT`. Shorter entropy inputs are rejected.
+    pub fn entropy_input_len(mut self, entropy_input_len: usize) -> Self {
+        self.entropy_input_len = Some(entropy_input_len);
+        self
+    }
+
+    /// How long to keep retrying entropy reads that return no bytes, see `Entropy::fill_partial`.
+    ///
+    /// Partial reads are always accumulated. Defaults to no waiting, an empty read fails with `DrbgError::EntropyTimeout`.
+    pub fn entropy_timeout(mut self, entropy_timeout: Duration) -> Self {
+        self.entropy_timeout = entropy_timeout;
+        self
+    }
+
+    /// How requests larger than max_number_of_bits_per_request are handled, see `RequestMode`.
+    ///
+    /// Defaults to `RequestMode::Chunked`.
+    pub fn request_mode(mut self, request_mode: RequestMode) -> Self {
+        self.request_mode = request_mode;
+        self
+    }
+
+    /// What happens after an entropy source failure, see `ErrorPolicy`.
+    ///
+    /// Defaults to `ErrorPolicy::Transient`.
+    pub fn error_policy(mut self, error_policy: ErrorPolicy) -> Self {
+        self.error_policy = error_policy;
+        self
+    }
+
+    /// Run the mechanism's known-answer self-test before its first instantiation in this process.
+    ///
+    /// A failure returns `DrbgError::SelfTestFailed` and puts every DRBG into the error state.
+    /// Mechanisms without `DrbgVariant::SELF_TEST_KAT` are refused with `DrbgError::NoKnownAnswer`.
+    pub fn self_test(mut self, self_test: bool) -> Self {
+        self.self_test = self_test;
+        self
+    }
+
+    /// Compare every generated block with the previous one (blocklen for CTR, outlen for Hash and HMAC).
+    ///
+    /// A repeated block returns `DrbgError::ContinuousTestFailed` and puts the DRBG into the error state.
+    /// The first block after instantiation is generated and discarded.
+    pub fn continuous_test(mut self, continuous_test: bool) -> Self {
+        self.continuous_test = continuous_test;
+        self
+    }
+
+    /// Mechanism specific instantiation parameters, checked by `DrbgVariant::valid_params`.
+    pub fn params(mut self, params: V::Params) -> Self {
+        self.params = params;
+        self
+    }
+
+    pub fn entropy<E2: Entropy>(self, entropy: E2) -> DrbgBuilder<'a, Pr, V, E2> {
+        DrbgBuilder {
+            personalization_string: self.personalization_string,
+            reseed_interval: self.reseed_interval,
+            nonce: self.nonce,
+            security_strength: self.security_strength,
+            entropy_input_len: self.entropy_input_len,
+            entropy_timeout: self.entropy_timeout,
+            request_mode: self.request_mode,
+            error_policy: self.error_policy,
+            self_test: self.self_test,
+            continuous_test: self.continuous_test,
+            params: self.params,
+            entropy,
+            _pr: PhantomData,
+        }
+    }
+}
+
+// Only allow the user to change the reseed interval if they are using a NoPr variant.
+// When Pr is enabled, the reseed interval need not be changed, we reseed after every call to generate.
+impl<'a, V: DrbgVariant, E> DrbgBuilder<'a, NoPr, V, E> {
+    pub fn reseed_interval(mut self, reseed_interval: u64) -> Self {
+        self.reseed_interval = Some(reseed_interval);
+        self
+    }
+}
+
+impl<'a, Pr: PredictionResistance, V: DrbgVariant + 'static, E: Entropy> DrbgBuilder<'a, Pr, V, E> {
+    /// Whether `build` would produce a DRBG in an approved (FIPS) configuration.
+    ///
+    /// Checks the mechanism, security strength, reseed interval, entropy source and nonce length.
+    pub fn is_approved(&self) -> bool {
+        let Some(security_strength) = (match self.security_strength {
+            Some(requested) => instantiation_security_strength::<V>(requested),
+            None => Some(V::SECURITY_STRENGTH),
+        }) else {
+            return false;
+        };
+        let nonce_len = match self.nonce {
+            Some(nonce) => nonce.len(),
+            None if V::USES_NONCE => security_strength / 2,
+            None => 0,
+        };
+        is_approved::<V, E>(
+            security_strength,
+            self.reseed_interval.unwrap_or(V::MAX_RESEED_INTERVAL),
+            nonce_len,
+        )
+    }
+
+    pub fn build(self) -> Result<Drbg<Pr, V, E>, DrbgError<E::Error>> {
+        self.instantiate(None)
+    }
+
+    /// Build the DRBG from caller-supplied entropy input instead of reading it from the entropy source.
+    ///
+    /// The entropy input must hold at least the minimum entropy for the security strength and at most MAX_ENTROPY bytes
+    /// (exactly seedlen for CTR DRBGs without df). The source is still used for a generated nonce and for later reseeds,
+    /// use `NoSource` to only ever reseed with `reseed_with_entropy_input`.
+    pub fn build_with_entropy_input(
+        self,
+        entropy_input: &[u8],
+    ) -> Result<Drbg<Pr, V, E>, DrbgError<E::Error>> {
+        self.instantiate(Some(entropy_input))
+    }
+
+    fn instantiate(
+        mut self,
+        entropy_input: Option<&[u8]>,
+    ) -> Result<Drbg<Pr, V, E>, DrbgError<E::Error>> {
+        // Section 11.3.1
+        if self.self_test && V::SELF_TEST_KAT.is_none() {
+            return Err(DrbgError::NoKnownAnswer);
+        }
+        if self_test::failed() || (self.self_test && !self_test::run_once::<V>()) {
+            return Err(DrbgError::SelfTestFailed);
+        }
+
+        // Parameters outside of the approved ranges are rejected by the checks below,
+        // the fips feature also refuses non-approved mechanisms and entropy sources.
+        #[cfg(feature = "fips")]
+        if !V::APPROVED.0 || E::APPROVED.is_none() {
+            return Err(DrbgError::NotApproved);
+        }
+
+        // Section 9.1 Step 2
+        if Pr::IS_PR && !E::PROVIDES_ENTROPY {
+            return Err(DrbgError::PredictionResistanceNotSupported);
+        }
+
+        // Section 9.1 Step 3
+        if self.personalization_string.len() > V::MAX_PERSONALIZATION_STRING_LENGTH {
+            return Err(DrbgError::PersonalizationStringTooLong);
+        }
+
+        if !V::valid_params(&self.params) {
+            return Err(DrbgError::InvalidParameters);
+        }
+
+        // Section 9.1 Steps 1 and 4
+        let security_strength = match self.security_strength {
+            Some(requested) => instantiation_security_strength::<V>(requested)
+                .ok_or(DrbgError::SecurityStrengthNotSupported)?,
+            None => V::SECURITY_STRENGTH,
+        };
+
+        // Section 8.6.5
+        if !entropy_source_supports::<V, E>(security_strength) {
+            return Err(DrbgError::EntropySourceTooWeak);
+        }
+
+        // Section 9.1 Step 6
+        let min_entropy = entropy_bytes::<E>(V::min_entropy(security_strength));
+        let entropy_input_len = self.entropy_input_len.unwrap_or(min_entropy);
+        if entropy_input_len < min_entropy {
+            return Err(DrbgError::EntropyInputTooShort);
+        } else if entropy_input_len > V::MAX_ENTROPY {
+            return Err(DrbgError::EntropyInputTooLong);
+        }
+
+        if let Some(entropy_input) = entropy_input {
+            if entropy_input.len() < V::min_entropy(security_strength) {
+                return Err(DrbgError::EntropyInputTooShort);
+            } else if entropy_input.len() > V::MAX_ENTROPY {
+                return Err(DrbgError::EntropyInputTooLong);
+            }
+        }
+
+        // Section 9.1 Step 8
+        let generated_nonce;
+        let nonce = match self.nonce {
+            // We assume that if the caller provided a nonce, it is acceptable (aside from length checks).
+            Some(nonce) => {
+                // Mechanisms that do not use a nonce only accept an empty one.
+                if !V::USES_NONCE {
+                    if !nonce.is_empty() {
+                        return Err(DrbgError::NonceTooLong);
+                    }
+                } else if nonce.len() < security_strength / 2 {
+                    return Err(DrbgError::NonceTooShort);
+                } else if nonce.len() > V::MAX_ENTROPY {
+                    return Err(DrbgError::NonceTooLong);
+                }
+                nonce
+            }
+            None if !V::USES_NONCE => &[],
+            // Section 8.6.7
+            // Otherwise, we generate a nonce using our entropy source with half security strength of min-entropy.
+            None => {
+                generated_nonce = {
+                    let mut nonce =
+                        Zeroizing::new(vec![0; entropy_bytes::<E>(security_strength / 2)]);
+                    fill_entropy(&mut self.entropy, &mut nonce, self.entropy_timeout)?;
+                    nonce
+                };
+                generated_nonce.as_slice()
+            }
+        };
+
+        // Section 9.1 Step 6
+        let gathered_entropy_input;
+        let entropy_input = match entropy_input {
+            Some(entropy_input) => entropy_input,
+            None => {
+                gathered_entropy_input = {
+                    let mut entropy_input = Zeroizing::new(vec![0; entropy_input_len]);
+                    fill_entropy(&mut self.entropy, &mut entropy_input, self.entropy_timeout)?;
+                    entropy_input
+                };
+                gathered_entropy_input.as_slice()
+            }
+        };
+
+        let mut drbg = Drbg::new(
+            self.entropy,
+            entropy_input,
+            nonce,
+            self.personalization_string,
+            &self.params,
+            security_strength,
+            entropy_input_len,
+        );
+
+        if let Some(reseed_interval) = self.reseed_interval {
+            if reseed_interval < 1 {
+                return Err(DrbgError::ReseedIntervalTooShort);
+            } else if reseed_interval > V::MAX_RESEED_INTERVAL {
+                return Err(DrbgError::ReseedIntervalTooLong);
+            }
+
+            drbg.set_reseed_interval(reseed_interval);
+        }
+        drbg.set_entropy_timeout(self.entropy_timeout);
+        drbg.set_request_mode(self.request_mode);
+        drbg.set_error_policy(self.error_policy);
+        if self.continuous_test {
+            drbg.enable_continuous_test()?;
+        }
+
+        Ok(drbg)
+    }
+}
@@ -1,12 +1,24 @@
 use crate::{
-    Entropy,
+    CryptoEntropy, Entropy,
     drbg::variant::{DrbgVariant, ReseedRequired},
     pr::PredictionResistance,
 };
-use std::marker::PhantomData;
+use rand_core::{TryCryptoRng, TryRngCore};
+use std::{
+    marker::PhantomData,
+    thread,
+    time::{Duration, Instant},
+};
+use zeroize::{Zeroize, Zeroizing};
 
+mod builder;
+mod rbg;
+pub mod self_test;
 pub mod variant;
 
+pub use builder::DrbgBuilder;
+pub use rbg::RandomBitGenerator;
+
 #[derive(Debug)]
 pub enum DrbgError<E> {
     ReseedIntervalTooLong,
@@ -15,6 +27,21 @@ pub enum DrbgError<E> {
     AdditionalInputTooLong,
     NonceTooLong,
     NonceTooShort,
+    SecurityStrengthNotSupported,
+    EntropyInputTooShort,
+    EntropyInputTooLong,
+    EntropySourceTooWeak,
+    EntropyTimeout,
+    ReseedRequired,
+    PredictionResistanceNotSupported,
+    InvalidParameters,
+    RequestTooLarge,
+    ErrorState,
+    SelfTestFailed,
+    NoKnownAnswer,
+    ContinuousTestFailed,
+    NotApproved,
+    UnknownMechanism,
     EntropyError(E),
 }
 
@@ -29,19 +56,163 @@ impl<E: std::fmt::Display> std::fmt::Display for DrbgError<E> {
                 write!(f, "Personalization string too long.")
             }
             DrbgError::AdditionalInputTooLong => write!(f, "Additional input too long."),
-            DrbgError::NonceTooLong => {
-                write!(f, "Nonce cannot be longer than {} bytes.", 1u64 << 32)
-            }
+            DrbgError::NonceTooLong => write!(f, "Nonce too long."),
             DrbgError::NonceTooShort => write!(
                 f,
                 "Nonce must be at least security_strength / 2 bytes long."
             ),
+            DrbgError::SecurityStrengthNotSupported => write!(
+                f,
+                "Requested security strength is not supported by this mechanism."
+            ),
+            DrbgError::EntropyInputTooShort => write!(
+                f,
+                "Entropy input must be at least the minimum entropy for the security strength."
+            ),
+            DrbgError::EntropyInputTooLong => write!(f, "Entropy input too long."),
+            DrbgError::EntropySourceTooWeak => write!(
+                f,
+                "Entropy source cannot support the requested security strength."
+            ),
+            DrbgError::EntropyTimeout => {
+                write!(f, "Entropy source did not provide enough bytes in time.")
+            }
+            DrbgError::ReseedRequired => write!(
+                f,
+                "Reseed required, entropy input must be supplied with reseed_with_entropy_input."
+            ),
+            DrbgError::PredictionResistanceNotSupported => write!(
+                f,
+                "Prediction resistance needs an entropy source to reseed from."
+            ),
+            DrbgError::InvalidParameters => write!(f, "Invalid mechanism parameters."),
+            DrbgError::RequestTooLarge => {
+                write!(f, "Requested bytes exceed max_number_of_bits_per_request.")
+            }
+            DrbgError::ErrorState => {
+                write!(f, "Drbg is in an error state and must be uninstantiated.")
+            }
+            DrbgError::SelfTestFailed => write!(f, "Known-answer self-test failed."),
+            DrbgError::NoKnownAnswer => {
+                write!(
+                    f,
+                    "Mechanism declares no known answer to self-test against."
+                )
+            }
+            DrbgError::ContinuousTestFailed => {
+                write!(f, "Continuous test failed, output block repeated.")
+            }
+            DrbgError::NotApproved => write!(f, "Configuration is not FIPS approved."),
+            DrbgError::UnknownMechanism => write!(f, "Unknown DRBG mechanism."),
             DrbgError::EntropyError(e) => write!(f, "Drbg Entropy Error: {e}"),
         }
     }
 }
 
-pub struct Variant<V> {
+// Section 8.4
+// SP 800-90A DRBGs support security strengths of 112, 128, 192 and 256 bits.
+const SECURITY_STRENGTHS: [usize; 4] = [14, 16, 24, 32];
+
+// Section 9.1 Steps 1 and 4
+// The requested strength (in bits) is rounded up to the nearest supported strength (in bytes).
+pub fn instantiation_security_strength<V: DrbgVariant>(requested: usize) -> Option<usize> {
+    if requested > V::SECURITY_STRENGTH * 8 {
+        return None;
+    }
+    SECURITY_STRENGTHS
+        .into_iter()
+        .find(|&security_strength| security_strength * 8 >= requested)
+}
+
+// Section 8.6.5
+// Whether the entropy source can support the security strength, CTR_DRBG without df also needs full entropy.
+pub fn entropy_source_supports<V: DrbgVariant, E: Entropy>(security_strength: usize) -> bool {
+    let min_entropy_per_bit = E::MIN_ENTROPY_PER_BIT;
+    security_strength * 8 <= E::MAX_SECURITY_STRENGTH
+        && min_entropy_per_bit > 0.0
+        && min_entropy_per_bit <= 1.0
+        && (!V::REQUIRES_FULL_ENTROPY || min_entropy_per_bit == 1.0)
+}
+
+// Number of bytes to read from the entropy source to obtain min_entropy bytes of min-entropy.
+pub fn entropy_bytes<E: Entropy>(min_entropy: usize) -> usize {
+    (min_entropy as f64 / E::MIN_ENTROPY_PER_BIT).ceil() as usize
+}
+
+// Pause between reads that returned no entropy.
+const ENTROPY_RETRY_INTERVAL: Duration = Duration::from_millis(1);
+
+// Accumulate partial reads until bytes is full.
+// Reads returning nothing are retried until entropy_timeout has passed, then the request fails with EntropyTimeout.
+pub fn fill_entropy<E: Entropy>(
+    entropy: &mut E,
+    bytes: &mut [u8],
+    entropy_timeout: Duration,
+) -> Result<(), DrbgError<E::Error>> {
+    let deadline = Instant::now().checked_add(entropy_timeout);
+    let mut filled = 0;
+    while filled < bytes.len() {
+        let read = entropy
+            .fill_partial(&mut bytes[filled..])
+            .map_err(DrbgError::EntropyError)?;
+        filled += read.min(bytes.len() - filled);
+        if read == 0 {
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return Err(DrbgError::EntropyTimeout);
+            }
+            thread::sleep(ENTROPY_RETRY_INTERVAL);
+        }
+    }
+    Ok(())
+}
+
+// Approved configurations as per SP 800-90A and SP 800-131A.
+// The mechanism and entropy source must be approved, the security strength at least 112 bits,
+// the reseed interval within Section 10 Tables 2 and 3 and the nonce at least security_strength / 2 bytes.
+pub fn is_approved<V: DrbgVariant, E: Entropy>(
+    security_strength: usize,
+    reseed_interval: u64,
+    nonce_len: usize,
+) -> bool {
+    let nonce_approved = if V::USES_NONCE {
+        nonce_len >= security_strength / 2
+    } else {
+        nonce_len == 0
+    };
+    V::APPROVED.0
+        && E::APPROVED.is_some()
+        && entropy_source_supports::<V, E>(security_strength)
+        && security_strength >= SECURITY_STRENGTHS[0]
+        && (1..=V::MAX_RESEED_INTERVAL).contains(&reseed_interval)
+        && nonce_approved
+}
+
+/// How requests larger than max_number_of_bits_per_request (Section 10 Tables 2 and 3) are handled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RequestMode {
+    /// Split the request into consecutive generate calls of at most max_number_of_bits_per_request.
+    ///
+    /// Additional input is only used by the first generate call (or the reseed before it),
+    /// later calls get a null additional input, as if the caller had issued them one by one.
+    #[default]
+    Chunked,
+    /// Reject the request with `DrbgError::RequestTooLarge`, as specified by Section 9.3.1 Step 2.
+    Strict,
+}
+
+/// What happens to a DRBG after a catastrophic failure such as an entropy source error or timeout (Section 11.3).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// The failing request returns the error, later requests are attempted again.
+    #[default]
+    Transient,
+    /// The DRBG enters an error state and every later generate or reseed returns `DrbgError::ErrorState`.
+    ///
+    /// The only way out is to uninstantiate and instantiate a new DRBG.
+    Latching,
+}
+
+pub struct Variant<V: DrbgVariant> {
     variant: V,
     reseed_counter: u64,
     reseed_interval: u64,
@@ -51,9 +222,14 @@ pub struct Variant<V> {
 // They all start with reseed_counter at 1, set it to 1 after a reseed, and add 1 to it after a generate.
 // I decided to abstract this behavior to simplify the variants.
 impl<V: DrbgVariant> Variant<V> {
-    fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Self {
+    fn instantiate(
+        params: &V::Params,
+        entropy_input: &[u8],
+        nonce: &[u8],
+        personalization_string: &[u8],
+    ) -> Self {
         Self {
-            variant: V::instantiate(entropy_input, nonce, personalization_string),
+            variant: V::instantiate(params, entropy_input, nonce, personalization_string),
             reseed_counter: 1,
             reseed_interval: V::MAX_RESEED_INTERVAL,
         }
@@ -85,73 +261,381 @@ impl<V: DrbgVariant> Variant<V> {
     }
 }
 
-pub struct Drbg<Pr, V, E> {
+// Section 9.4
+// The working state is wiped whenever it goes out of scope, including on uninstantiate.
+impl<V: DrbgVariant> Drop for Variant<V> {
+    fn drop(&mut self) {
+        self.variant.zeroize();
+        self.reseed_counter.zeroize();
+    }
+}
+
+/// DRBG over any `DrbgVariant` mechanism, with prediction resistance `Pr` and entropy source `E`.
+///
+/// Handles the reseed counter, prediction resistance, request chunking, error state and self-tests
+/// shared by every mechanism. Create one with `Drbg::builder()`.
+pub struct Drbg<Pr, V: DrbgVariant, E> {
     variant: Variant<V>,
+    security_strength: usize,
+    entropy_input_len: usize,
+    nonce_len: usize,
+    entropy_timeout: Duration,
+    request_mode: RequestMode,
+    error_policy: ErrorPolicy,
+    error_state: bool,
+    // Previous output block for the continuous test, None when the test is disabled.
+    // Wiped when dropped, like the working state.
+    last_block: Option<Zeroizing<Vec<u8>>>,
     entropy: E,
     _pr: PhantomData<Pr>,
 }
 
 impl<Pr: PredictionResistance, V: DrbgVariant, E: Entropy> Drbg<Pr, V, E> {
-    pub fn set_reseed_interval(&mut self, reseed_interval: u64) {
+    pub(crate) fn set_reseed_interval(&mut self, reseed_interval: u64) {
         self.variant.reseed_interval = reseed_interval;
     }
 
+    pub(crate) fn set_entropy_timeout(&mut self, entropy_timeout: Duration) {
+        self.entropy_timeout = entropy_timeout;
+    }
+
+    pub(crate) fn set_request_mode(&mut self, request_mode: RequestMode) {
+        self.request_mode = request_mode;
+    }
+
+    pub(crate) fn set_error_policy(&mut self, error_policy: ErrorPolicy) {
+        self.error_policy = error_policy;
+    }
+
+    /// Security strength in bits this DRBG was instantiated at.
+    pub fn security_strength(&self) -> usize {
+        self.security_strength * 8
+    }
+
+    /// Whether this DRBG runs in an approved (FIPS) configuration.
+    ///
+    /// Checks the mechanism, security strength, reseed interval, entropy source and nonce length.
+    pub fn is_approved(&self) -> bool {
+        is_approved::<V, E>(
+            self.security_strength,
+            self.variant.reseed_interval,
+            self.nonce_len,
+        )
+    }
+
+    /// Whether the DRBG refuses requests because it entered the error state.
+    ///
+    /// A failed continuous test puts this DRBG into the error state whatever the `ErrorPolicy`,
+    /// and a failed self-test puts every DRBG into it.
+    /// Entropy source failures and timeouts only do so under `ErrorPolicy::Latching`.
+    pub fn is_in_error_state(&self) -> bool {
+        self.error_state || self_test::failed()
+    }
+
+    /// Run the mechanism's known-answer self-test on demand (Section 11.3).
+    ///
+    /// A failure returns `DrbgError::SelfTestFailed` and puts every DRBG into the error state.
+    /// Mechanisms without `DrbgVariant::SELF_TEST_KAT` cannot be tested and return `DrbgError::NoKnownAnswer`.
+    pub fn self_test(&mut self) -> Result<(), DrbgError<E::Error>>
+    where
+        V: 'static,
+    {
+        if V::SELF_TEST_KAT.is_none() {
+            return Err(DrbgError::NoKnownAnswer);
+        }
+        if !self_test::run::<V>() {
+            self.error_state = true;
+            return Err(DrbgError::SelfTestFailed);
+        }
+        Ok(())
+    }
+
+    // Section 11.3
+    // Catastrophic errors put a latching DRBG into the error state.
+    // A source that times out failed to provide entropy just like one returning an error.
+    fn fail(&mut self, error: DrbgError<E::Error>) -> DrbgError<E::Error> {
+        if self.error_policy == ErrorPolicy::Latching
+            && matches!(
+                error,
+                DrbgError::EntropyError(_) | DrbgError::EntropyTimeout
+            )
+        {
+            self.error_state = true;
+        }
+        error
+    }
+
+    // Continuous random number generator test on every OUTPUT_BLOCK_LEN block of output.
+    // The first block is generated and discarded to have something to compare against.
+    pub(crate) fn enable_continuous_test(&mut self) -> Result<(), DrbgError<E::Error>> {
+        let mut first_block = Zeroizing::new(vec![0; V::OUTPUT_BLOCK_LEN]);
+        self.generate_request(&mut first_block, &[], Pr::IS_PR)?;
+        self.last_block = Some(first_block);
+        Ok(())
+    }
+
+    // Fails and enters the error state if any block equals the block before it.
+    fn continuous_test(&mut self, output: &[u8]) -> Result<(), DrbgError<E::Error>> {
+        if let Some(last_block) = &mut self.last_block {
+            for block in output.chunks(V::OUTPUT_BLOCK_LEN) {
+                if block == last_block.as_slice() {
+                    self.error_state = true;
+                    return Err(DrbgError::ContinuousTestFailed);
+                }
+                last_block.copy_from_slice(block);
+            }
+        }
+        Ok(())
+    }
+
+    // Chunked requests are split on whole blocks with the continuous test, so every block can be compared.
+    // Strict requests are a single generate call up to the mechanism's limit.
+    fn chunk_len(&self) -> usize {
+        let max_bytes_per_request = self.variant.variant.max_bytes_per_request();
+        if self.request_mode == RequestMode::Chunked && self.last_block.is_some() {
+            max_bytes_per_request - max_bytes_per_request % V::OUTPUT_BLOCK_LEN
+        } else {
+            max_bytes_per_request
+        }
+    }
+
     // Section 9.1
-    pub fn new(
-        mut entropy: E,
+    // The builder gathers or checks the entropy input (Step 6) and the nonce (Step 8).
+    pub(crate) fn new(
+        entropy: E,
+        entropy_input: &[u8],
         nonce: &[u8],
         personalization_string: &[u8],
-    ) -> Result<Self, DrbgError<E::Error>> {
-        // Section 9.1 Step 6
-        // We always use MIN_ENTROPY here for simplicity. Our entropy will be conditioned by df anyway.
-        let mut entropy_input = vec![0; V::MIN_ENTROPY];
-        entropy
-            .fill_bytes(&mut entropy_input)
-            .map_err(DrbgError::EntropyError)?;
-        Ok(Self {
+        params: &V::Params,
+        security_strength: usize,
+        entropy_input_len: usize,
+    ) -> Self {
+        Self {
             // Section 9.1 Step 9
-            variant: Variant::instantiate(&entropy_input, nonce, personalization_string),
+            variant: Variant::instantiate(params, entropy_input, nonce, personalization_string),
+            security_strength,
+            entropy_input_len,
+            nonce_len: nonce.len(),
+            entropy_timeout: Duration::ZERO,
+            request_mode: RequestMode::default(),
+            error_policy: ErrorPolicy::default(),
+            error_state: false,
+            last_block: None,
             entropy,
             _pr: PhantomData,
-        })
+        }
     }
 
-    // Section 9.2
-    fn reseed(&mut self, additional_input: &[u8]) -> Result<(), DrbgError<E::Error>> {
+    /// Reseed the DRBG from its entropy source (Section 9.2).
+    pub fn reseed(&mut self) -> Result<(), DrbgError<E::Error>> {
+        self.reseed_with_ai(&[])
+    }
+
+    /// Reseed the DRBG from its entropy source (Section 9.2).
+    ///
+    /// `additional_input` will be factored into the new state.
+    /// Fails with `AdditionalInputTooLong`, `ReseedRequired` or `EntropyError`, in which case the state is left unchanged.
+    pub fn reseed_with_ai(&mut self, additional_input: &[u8]) -> Result<(), DrbgError<E::Error>> {
+        if self.is_in_error_state() {
+            return Err(DrbgError::ErrorState);
+        }
+        // Section 9.2 Step 2
+        if additional_input.len() > V::MAX_ADDITIONAL_INPUT_LENGTH {
+            return Err(DrbgError::AdditionalInputTooLong);
+        }
+        // Without a source the caller has to supply the entropy input, this is not an entropy source failure.
+        if !E::PROVIDES_ENTROPY {
+            return Err(DrbgError::ReseedRequired);
+        }
         // Section 9.2 Step 4
-        // We always use MIN_ENTROPY here for simplicity. Our entropy will be conditioned by df anyway.
-        let mut entropy_input = vec![0; V::MIN_ENTROPY];
-        self.entropy
-            .fill_bytes(&mut entropy_input)
-            .map_err(DrbgError::EntropyError)?;
+        let mut entropy_input = Zeroizing::new(vec![0; self.entropy_input_len]);
+        if let Err(e) = fill_entropy(&mut self.entropy, &mut entropy_input, self.entropy_timeout) {
+            return Err(self.fail(e));
+        }
         // Section 9.2 Step 5
         self.variant.reseed(&entropy_input, additional_input);
         Ok(())
     }
 
+    /// Reseed the DRBG from caller-supplied entropy input instead of reading it from the entropy source.
+    ///
+    /// The entropy input must hold at least the minimum entropy for the security strength and at most MAX_ENTROPY bytes.
+    /// `additional_input` will be factored into the new state.
+    pub fn reseed_with_entropy_input(
+        &mut self,
+        entropy_input: &[u8],
+        additional_input: &[u8],
+    ) -> Result<(), DrbgError<E::Error>> {
+        if self.is_in_error_state() {
+            return Err(DrbgError::ErrorState);
+        }
+        // Section 9.2 Step 2
+        if additional_input.len() > V::MAX_ADDITIONAL_INPUT_LENGTH {
+            return Err(DrbgError::AdditionalInputTooLong);
+        }
+        if entropy_input.len() < V::min_entropy(self.security_strength) {
+            return Err(DrbgError::EntropyInputTooShort);
+        } else if entropy_input.len() > V::MAX_ENTROPY {
+            return Err(DrbgError::EntropyInputTooLong);
+        }
+        // Section 9.2 Step 5
+        self.variant.reseed(entropy_input, additional_input);
+        Ok(())
+    }
+
+    /// Uninstantiate the DRBG, zeroizing its internal state, and hand back the entropy source (Section 9.4).
+    pub fn uninstantiate(self) -> E {
+        // Dropping the working state and the continuous test block zeroizes them.
+        let Self {
+            variant,
+            last_block,
+            entropy,
+            ..
+        } = self;
+        drop(variant);
+        drop(last_block);
+        entropy
+    }
+
+    /// Fill bytes array with random bits.
+    pub fn fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), DrbgError<E::Error>> {
+        self.fill_bytes_with_ai(bytes, &[])
+    }
+
+    /// Fill bytes array with random bits.
+    ///
+    /// `additional_input` will be factored into the bit generation.
+    pub fn fill_bytes_with_ai(
+        &mut self,
+        bytes: &mut [u8],
+        additional_input: &[u8],
+    ) -> Result<(), DrbgError<E::Error>> {
+        self.generate(bytes, additional_input, Pr::IS_PR)
+    }
+
+    /// Fill bytes array with random bits, reseeding from the entropy source first.
+    ///
+    /// Gives prediction resistance for this request only, even on `NoPr` DRBGs.
+    /// `additional_input` will be factored into the reseed.
+    pub fn fill_bytes_pr(
+        &mut self,
+        bytes: &mut [u8],
+        additional_input: &[u8],
+    ) -> Result<(), DrbgError<E::Error>> {
+        self.generate(bytes, additional_input, true)
+    }
+
     // Section 9.3
-    pub fn fill_bytes(
+    fn generate(
         &mut self,
         bytes: &mut [u8],
         additional_input: &[u8],
+        prediction_resistance_request: bool,
     ) -> Result<(), DrbgError<E::Error>> {
+        if self.is_in_error_state() {
+            return Err(DrbgError::ErrorState);
+        }
         if additional_input.len() > V::MAX_ADDITIONAL_INPUT_LENGTH {
             return Err(DrbgError::AdditionalInputTooLong);
         }
         // Section 9.3.1 Step 2
-        // We operate over MAX_BYTES_PER_REQUEST chunks so if we need to reseed, we do.
-        for block in bytes.chunks_mut(V::MAX_BYTES_PER_REQUEST) {
-            // Section 9.3.1 Step 7
-            if Pr::IS_PR || self.variant.generate(block, additional_input).is_err() {
-                // Section 9.3.1 Step 7.1
-                self.reseed(additional_input)?;
-                // Section 9.3.1 Step 7.4
-                // We call generate_unchecked to avoid the redundant reseed_counter check.
-                // reseed_counter is guaranteed to be 1, we just reseeded.
-                // If additional_input was passed into reseed, it is null in the call to generate.
-                self.variant.generate_unchecked(block, &[]);
+        if self.request_mode == RequestMode::Strict
+            && bytes.len() > self.variant.variant.max_bytes_per_request()
+        {
+            return Err(DrbgError::RequestTooLarge);
+        }
+        // In chunked mode we operate over chunk_len chunks so if we need to reseed, we do.
+        let mut additional_input = additional_input;
+        for block in bytes.chunks_mut(self.chunk_len()) {
+            if self.last_block.is_some() {
+                // Rounding up to whole blocks leaves the working state as if only block.len() bytes were generated.
+                // Bytes past block.len() are never returned, the buffer is wiped when dropped.
+                let mut output =
+                    Zeroizing::new(vec![0; block.len().next_multiple_of(V::OUTPUT_BLOCK_LEN)]);
+                self.generate_request(
+                    &mut output,
+                    additional_input,
+                    prediction_resistance_request,
+                )?;
+                self.continuous_test(&output)?;
+                block.copy_from_slice(&output[..block.len()]);
+            } else {
+                self.generate_request(block, additional_input, prediction_resistance_request)?;
             }
+            // The additional input belongs to the whole request, not to every chunk.
+            additional_input = &[];
         }
         Ok(())
     }
+
+    // Section 9.3.1 Steps 7 and 8 for a single request.
+    fn generate_request(
+        &mut self,
+        bytes: &mut [u8],
+        additional_input: &[u8],
+        prediction_resistance_request: bool,
+    ) -> Result<(), DrbgError<E::Error>> {
+        // Section 9.3.1 Step 7
+        if prediction_resistance_request || self.variant.generate(bytes, additional_input).is_err()
+        {
+            // Section 9.3.1 Step 7.1
+            self.reseed_with_ai(additional_input)?;
+            // Section 9.3.1 Step 7.4
+            // We call generate_unchecked to avoid the redundant reseed_counter check.
+            // reseed_counter is guaranteed to be 1, we just reseeded.
+            // If additional_input was passed into reseed, it is null in the call to generate.
+            self.variant.generate_unchecked(bytes, &[]);
+        }
+        Ok(())
+    }
+}
+
+impl<Pr: PredictionResistance, V: DrbgVariant, E: Entropy> TryRngCore for Drbg<Pr, V, E> {
+    type Error = DrbgError<E::Error>;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        let mut bytes = [0; std::mem::size_of::<u32>()];
+        self.try_fill_bytes(&mut bytes)?;
+        Ok(u32::from_ne_bytes(bytes))
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        let mut bytes = [0; std::mem::size_of::<u64>()];
+        self.try_fill_bytes(&mut bytes)?;
+        Ok(u64::from_ne_bytes(bytes))
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        self.fill_bytes(dst)
+    }
+}
+
+impl<Pr: PredictionResistance, V: DrbgVariant, E: CryptoEntropy> TryCryptoRng for Drbg<Pr, V, E> {}
+
+impl<Pr: PredictionResistance, V: DrbgVariant, E: Entropy> RandomBitGenerator for Drbg<Pr, V, E> {
+    type Error = DrbgError<E::Error>;
+
+    fn fill_bytes_with_ai(
+        &mut self,
+        bytes: &mut [u8],
+        additional_input: &[u8],
+    ) -> Result<(), Self::Error> {
+        Drbg::fill_bytes_with_ai(self, bytes, additional_input)
+    }
+
+    fn reseed_with_ai(&mut self, additional_input: &[u8]) -> Result<(), Self::Error> {
+        Drbg::reseed_with_ai(self, additional_input)
+    }
+
+    fn security_strength(&self) -> usize {
+        Drbg::security_strength(self)
+    }
+
+    fn mechanism(&self) -> &'static str {
+        V::NAME
+    }
+
+    fn prediction_resistance(&self) -> bool {
+        Pr::IS_PR
+    }
 }
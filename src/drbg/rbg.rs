@@ -0,0 +1,35 @@
+/// Operations shared by every DRBG type, the generated ones (e.g. `DrbgCtrAes256`, `DrbgPrHmacSha512`) and the generic `Drbg`.
+///
+/// Object safe, so DRBGs of different mechanisms can be stored as `Box<dyn RandomBitGenerator<Error = ...>>`.
+pub trait RandomBitGenerator {
+    type Error;
+
+    /// Fill bytes array with random bits.
+    fn fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        self.fill_bytes_with_ai(bytes, &[])
+    }
+
+    /// Fill bytes array with random bits, `additional_input` will be factored into the bit generation.
+    fn fill_bytes_with_ai(
+        &mut self,
+        bytes: &mut [u8],
+        additional_input: &[u8],
+    ) -> Result<(), Self::Error>;
+
+    /// Reseed the DRBG from its entropy source.
+    fn reseed(&mut self) -> Result<(), Self::Error> {
+        self.reseed_with_ai(&[])
+    }
+
+    /// Reseed the DRBG from its entropy source, `additional_input` will be factored into the new state.
+    fn reseed_with_ai(&mut self, additional_input: &[u8]) -> Result<(), Self::Error>;
+
+    /// Security strength in bits this DRBG was instantiated at.
+    fn security_strength(&self) -> usize;
+
+    /// Mechanism name as in the CAVP response files, e.g. `"CTR_DRBG AES-256 use df"` or `"HMAC_DRBG SHA-512/256"`.
+    fn mechanism(&self) -> &'static str;
+
+    /// Whether every request reseeds from the entropy source.
+    fn prediction_resistance(&self) -> bool;
+}
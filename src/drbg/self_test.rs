@@ -0,0 +1,95 @@
+use crate::drbg::{Variant, variant::DrbgVariant};
+use std::{
+    any::TypeId,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+// Mechanisms whose known-answer test has passed in this process.
+static PASSED: Mutex<Vec<TypeId>> = Mutex::new(Vec::new());
+// Set once any known-answer test fails, every DRBG is then in the error state.
+static FAILED: AtomicBool = AtomicBool::new(false);
+
+// Decode a hex string at compile time, used for the embedded known answers.
+pub(crate) const fn hex<const N: usize>(s: &str) -> [u8; N] {
+    const fn nibble(c: u8) -> u8 {
+        match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            _ => panic!("invalid hex digit"),
+        }
+    }
+    let s = s.as_bytes();
+    assert!(s.len() == 2 * N);
+    let mut bytes = [0; N];
+    let mut i = 0;
+    while i < N {
+        bytes[i] = (nibble(s[2 * i]) << 4) | nibble(s[2 * i + 1]);
+        i += 1;
+    }
+    bytes
+}
+
+fn sequence(start: u8, len: usize) -> Vec<u8> {
+    (0..len).map(|i| start.wrapping_add(i as u8)).collect()
+}
+
+// Section 11.3.2 to 11.3.5
+// Instantiate, generate, reseed and generate again from fixed inputs at the highest security strength,
+// compare the last output against the embedded known answer, then check uninstantiate zeroizes the state.
+fn known_answer_test<V: DrbgVariant>() -> bool {
+    let security_strength = V::SECURITY_STRENGTH;
+    let entropy_input_len = V::min_entropy(security_strength);
+    let nonce = if V::USES_NONCE {
+        sequence(0x20, security_strength / 2)
+    } else {
+        Vec::new()
+    };
+    let personalization_string = sequence(0x40, security_strength);
+    let additional_input = sequence(0x60, security_strength);
+
+    let mut variant = Variant::<V>::instantiate(
+        &V::Params::default(),
+        &sequence(0x00, entropy_input_len),
+        &nonce,
+        &personalization_string,
+    );
+    let mut bytes = [0; 32];
+    variant.generate_unchecked(&mut bytes, &additional_input);
+    variant.reseed(&sequence(0x80, entropy_input_len), &additional_input);
+    variant.generate_unchecked(&mut bytes, &additional_input);
+    if V::SELF_TEST_KAT != Some(bytes) {
+        return false;
+    }
+
+    variant.variant.zeroize();
+    variant.variant.is_zeroized()
+}
+
+// Run the known-answer test for V, latching every DRBG into the error state on failure.
+pub fn run<V: DrbgVariant + 'static>() -> bool {
+    if !known_answer_test::<V>() {
+        FAILED.store(true, Ordering::Relaxed);
+        return false;
+    }
+    let mut passed = PASSED.lock().unwrap_or_else(|e| e.into_inner());
+    if !passed.contains(&TypeId::of::<V>()) {
+        passed.push(TypeId::of::<V>());
+    }
+    true
+}
+
+// Run the known-answer test for V unless it already passed in this process.
+pub fn run_once<V: DrbgVariant + 'static>() -> bool {
+    let passed = PASSED
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .contains(&TypeId::of::<V>());
+    passed || run::<V>()
+}
+
+pub fn failed() -> bool {
+    FAILED.load(Ordering::Relaxed)
+}
@@ -1,17 +1,105 @@
 #[derive(Debug)]
 pub struct ReseedRequired;
 
+mod sealed {
+    // Approval for approved (FIPS) configurations, granted only to this crate's own block ciphers and hash functions.
+    // Other crates cannot name the type, so their mechanisms always keep the default of not approved.
+    #[derive(Clone, Copy, Debug)]
+    pub struct Approval(pub(crate) bool);
+}
+
+pub(crate) use sealed::Approval;
+
+// Mechanism name joined from its parts at compile time, e.g. "CTR_DRBG " + "AES-256" + " use df".
+pub(crate) struct MechanismName {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl MechanismName {
+    pub(crate) const fn join(parts: &[&str]) -> Self {
+        let mut bytes = [0; 64];
+        let mut len = 0;
+        let mut i = 0;
+        while i < parts.len() {
+            let part = parts[i].as_bytes();
+            let mut j = 0;
+            while j < part.len() {
+                bytes[len] = part[j];
+                len += 1;
+                j += 1;
+            }
+            i += 1;
+        }
+        Self { bytes, len }
+    }
+
+    pub(crate) const fn as_str(&self) -> &str {
+        match std::str::from_utf8(self.bytes.split_at(self.len).0) {
+            Ok(name) => name,
+            Err(_) => panic!("mechanism name is not UTF-8"),
+        }
+    }
+}
+
+/// A DRBG mechanism (e.g. CTR_DRBG, Hash_DRBG, HMAC_DRBG) plugged into the generic `Drbg`.
+///
+/// Lengths and security strengths are in bytes. `Drbg` checks the input lengths against the constants
+/// before calling into the mechanism and handles the reseed counter, so `generate` only produces output.
 pub trait DrbgVariant {
+    /// Mechanism name as in the CAVP response files, e.g. `"CTR_DRBG AES-256 use df"`, see `RandomBitGenerator::mechanism`.
+    const NAME: &'static str;
+    /// Maximum number of generate requests between reseeds.
     const MAX_RESEED_INTERVAL: u64;
+    /// Highest supported security strength.
     const SECURITY_STRENGTH: usize;
 
-    const MIN_ENTROPY: usize = Self::SECURITY_STRENGTH;
     const MAX_ENTROPY: usize = 1 << 32;
     const MAX_PERSONALIZATION_STRING_LENGTH: usize = 1 << 32;
     const MAX_ADDITIONAL_INPUT_LENGTH: usize = 1 << 32;
     const MAX_BYTES_PER_REQUEST: usize = 1 << 16;
+    /// Output block length compared by the continuous test (blocklen for CTR_DRBG, outlen for the hash based DRBGs).
+    const OUTPUT_BLOCK_LEN: usize;
+    /// Whether the mechanism may be used in an approved (FIPS) configuration.
+    ///
+    /// Only the mechanisms over this crate's AES and SHA types are approved, it cannot be set by other crates.
+    const APPROVED: Approval = Approval(false);
+    /// Whether the mechanism takes a nonce during instantiation (CTR_DRBG without df does not).
+    const USES_NONCE: bool = true;
+    /// Whether the entropy input must be full entropy (CTR_DRBG without df).
+    const REQUIRES_FULL_ENTROPY: bool = false;
+
+    /// Mechanism specific instantiation parameters (e.g. ctr_len for CTR_DRBG).
+    type Params: Default + Copy;
+    fn valid_params(_params: &Self::Params) -> bool {
+        true
+    }
 
-    fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Self;
+    /// Minimum entropy input length when instantiated at security_strength.
+    fn min_entropy(security_strength: usize) -> usize {
+        security_strength
+    }
+
+    fn instantiate(
+        params: &Self::Params,
+        entropy_input: &[u8],
+        nonce: &[u8],
+        personalization_string: &[u8],
+    ) -> Self;
     fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]);
     fn generate(&mut self, bytes: &mut [u8], additional_input: &[u8], reseed_counter: u64);
+    /// Overwrite the secret working state (e.g. V, Key, C) with zeros (Section 9.4).
+    fn zeroize(&mut self);
+    fn is_zeroized(&self) -> bool;
+
+    /// Last 32 bytes generated by the known-answer self-test at SECURITY_STRENGTH:
+    /// instantiate, generate, reseed and generate again from fixed input sequences.
+    ///
+    /// Without a known answer, self-tests are refused with `DrbgError::NoKnownAnswer`.
+    const SELF_TEST_KAT: Option<[u8; 32]> = None;
+
+    /// Some parameters shrink max_number_of_bits_per_request below MAX_BYTES_PER_REQUEST.
+    fn max_bytes_per_request(&self) -> usize {
+        Self::MAX_BYTES_PER_REQUEST
+    }
 }
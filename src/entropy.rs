@@ -4,17 +4,92 @@ use std::fmt::{Debug, Display};
 pub trait Entropy {
     type Error: Display + Debug;
 
+    /// Set to `Some(Approved::source::<Self>())` if the source may back an approved (FIPS) configuration.
+    ///
+    /// Only `CryptoEntropy` sources can be approved, and only those validated for the required min-entropy should be.
+    const APPROVED: Option<Approved> = None;
+
+    /// Claimed min-entropy per output bit, in (0, 1].
+    ///
+    /// Defaults to full entropy, sources with less are over-sampled to reach the required min-entropy.
+    const MIN_ENTROPY_PER_BIT: f64 = 1.0;
+
+    /// Highest security strength in bits the source can support.
+    const MAX_SECURITY_STRENGTH: usize = 256;
+
+    /// Whether the source can be read at all.
+    ///
+    /// Without one, reseeds that would read from it fail with `DrbgError::ReseedRequired`
+    /// and prediction resistant DRBGs cannot be built.
+    const PROVIDES_ENTROPY: bool = true;
+
     fn fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Fill as much of `bytes` as is available, returning the number of bytes written.
+    ///
+    /// Sources that return partial data or would block (e.g. `EAGAIN`) override this and return what they have,
+    /// possibly 0. The DRBG keeps reading until it has all the bytes it needs. Defaults to `fill_bytes`.
+    fn fill_partial(&mut self, bytes: &mut [u8]) -> Result<usize, Self::Error> {
+        self.fill_bytes(bytes)?;
+        Ok(bytes.len())
+    }
 }
 
 pub trait CryptoEntropy: Entropy {}
 
+/// Approval of an entropy source, see `Entropy::APPROVED`.
+///
+/// Can only be created for `CryptoEntropy` sources.
+#[derive(Clone, Copy, Debug)]
+pub struct Approved(());
+
+impl Approved {
+    /// Approve the entropy source `E`.
+    pub const fn source<E: CryptoEntropy>() -> Self {
+        Self(())
+    }
+}
+
 impl Entropy for OsRng {
     type Error = <OsRng as TryRngCore>::Error;
 
+    const APPROVED: Option<Approved> = Some(Approved::source::<Self>());
+
     fn fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), Self::Error> {
         self.try_fill_bytes(bytes)
     }
 }
 
 impl CryptoEntropy for OsRng {}
+
+/// Placeholder for DRBGs without an entropy source.
+///
+/// Instantiate with `build_with_entropy_input` and reseed with `reseed_with_entropy_input`,
+/// any reseed that would read from the source (prediction resistance, reseed interval, `reseed`) fails with
+/// `DrbgError::ReseedRequired`, which never puts the DRBG into the error state.
+/// Prediction resistant DRBGs are refused with `DrbgError::PredictionResistanceNotSupported`.
+/// Nothing vouches for caller-supplied entropy input, so the source is not approved.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoSource;
+
+#[derive(Debug)]
+pub struct NoSourceError;
+
+impl Display for NoSourceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "No entropy source, entropy input must be supplied by the caller."
+        )
+    }
+}
+
+impl Entropy for NoSource {
+    type Error = NoSourceError;
+
+    const PROVIDES_ENTROPY: bool = false;
+
+    fn fill_bytes(&mut self, _bytes: &mut [u8]) -> Result<(), Self::Error> {
+        Err(NoSourceError)
+    }
+}
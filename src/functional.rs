@@ -0,0 +1,216 @@
+//! SP 800-90A Section 9 functional interface.
+//!
+//! DRBG instances live in a `DrbgTable` and are referred to by opaque `StateHandle`s.
+//! Every function returns a spec-style `Status` instead of a `DrbgError`.
+
+use crate::{AnyDrbg, DrbgError, Entropy, ErrorPolicy, RequestMode, for_each_mechanism};
+use rand_core::OsRng;
+use std::collections::HashMap;
+
+/// Status returned by the functional interface (Section 9).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Success,
+    /// The request was invalid, the instance can still be used.
+    ErrorFlag,
+    /// The entropy source failed, the instance is in the error state and must be uninstantiated.
+    CatastrophicErrorFlag,
+}
+
+/// Opaque handle to an instantiated DRBG in a `DrbgTable`.
+///
+/// Handles are never reused, so a handle stays invalid after its instance is uninstantiated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StateHandle(u64);
+
+fn status<E>(error: DrbgError<E>) -> Status {
+    match error {
+        DrbgError::EntropyError(_)
+        | DrbgError::EntropyTimeout
+        | DrbgError::ErrorState
+        | DrbgError::SelfTestFailed
+        | DrbgError::ContinuousTestFailed => Status::CatastrophicErrorFlag,
+        _ => Status::ErrorFlag,
+    }
+}
+
+macro_rules! define_mechanisms {
+    ($($(#[$attr:meta])* ($mechanism:ident, $pr_mechanism:ident, $name:ident, $builder:ident, $pr_name:ident, $pr_builder:ident, $variant:ident, $inner:ident)),*$(,)?) => {
+        /// DRBG mechanisms that can be instantiated through the functional interface.
+        ///
+        /// Instances always use the `Drbg` types, the prediction_resistance_flag passed to
+        /// `DrbgTable::instantiate_function` only permits prediction resistance requests.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum Mechanism {
+            $($(#[$attr])* $mechanism,)*
+        }
+
+        // Instances reject oversized requests instead of splitting them, like Section 9.3.1 Step 2,
+        // enter the error state after a catastrophic error, like Section 11.3,
+        // and each mechanism is self-tested before its first instantiation, like Section 11.3.1.
+        fn instantiate<E: Entropy>(
+            mechanism: Mechanism,
+            requested_instantiation_security_strength: usize,
+            personalization_string: &[u8],
+            entropy: E,
+        ) -> Result<AnyDrbg<E>, Status> {
+            match mechanism {
+                $(
+                    $(#[$attr])*
+                    Mechanism::$mechanism => crate::$name::builder()
+                        .entropy(entropy)
+                        .security_strength(requested_instantiation_security_strength)
+                        .personalization_string(personalization_string)
+                        .request_mode(RequestMode::Strict)
+                        .error_policy(ErrorPolicy::Latching)
+                        .self_test(true)
+                        .build()
+                        .map(AnyDrbg::$mechanism)
+                        .map_err(status),
+                )*
+            }
+        }
+    };
+}
+
+for_each_mechanism!(define_mechanisms);
+
+// Instance together with the prediction_resistance_flag it was instantiated with.
+struct Entry<E> {
+    drbg: AnyDrbg<E>,
+    prediction_resistance_flag: bool,
+}
+
+/// Table of DRBG instances addressed by `StateHandle`.
+///
+/// Every instance gets its own clone of the entropy source.
+pub struct DrbgTable<E = OsRng> {
+    instances: HashMap<StateHandle, Entry<E>>,
+    next_handle: u64,
+    entropy: E,
+}
+
+impl DrbgTable {
+    /// Create an empty table whose instances use the OsRng entropy source.
+    pub fn new() -> Self {
+        Self::with_entropy(OsRng)
+    }
+}
+
+impl Default for DrbgTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Entropy + Clone> DrbgTable<E> {
+    /// Create an empty table whose instances use clones of `entropy`.
+    pub fn with_entropy(entropy: E) -> Self {
+        Self {
+            instances: HashMap::new(),
+            next_handle: 0,
+            entropy,
+        }
+    }
+
+    /// Section 9.1 Instantiate_function.
+    ///
+    /// `requested_instantiation_security_strength` is in bits.
+    pub fn instantiate_function(
+        &mut self,
+        mechanism: Mechanism,
+        requested_instantiation_security_strength: usize,
+        prediction_resistance_flag: bool,
+        personalization_string: &[u8],
+    ) -> (Status, Option<StateHandle>) {
+        match instantiate(
+            mechanism,
+            requested_instantiation_security_strength,
+            personalization_string,
+            self.entropy.clone(),
+        ) {
+            Ok(drbg) => {
+                let state_handle = StateHandle(self.next_handle);
+                self.next_handle += 1;
+                self.instances.insert(
+                    state_handle,
+                    Entry {
+                        drbg,
+                        prediction_resistance_flag,
+                    },
+                );
+                (Status::Success, Some(state_handle))
+            }
+            Err(status) => (status, None),
+        }
+    }
+
+    /// Section 9.3.1 Generate_function.
+    ///
+    /// `requested_number_of_bits` must be a multiple of 8 and `requested_security_strength` is in bits.
+    pub fn generate_function(
+        &mut self,
+        state_handle: StateHandle,
+        requested_number_of_bits: usize,
+        requested_security_strength: usize,
+        prediction_resistance_request: bool,
+        additional_input: &[u8],
+    ) -> (Status, Option<Vec<u8>>) {
+        // Section 9.3.1 Step 1
+        let Some(entry) = self.instances.get_mut(&state_handle) else {
+            return (Status::ErrorFlag, None);
+        };
+        // Section 9.3.1 Steps 3 and 5
+        if !requested_number_of_bits.is_multiple_of(8)
+            || requested_security_strength > entry.drbg.security_strength()
+            || (prediction_resistance_request && !entry.prediction_resistance_flag)
+        {
+            return (Status::ErrorFlag, None);
+        }
+        let mut pseudorandom_bits = vec![0; requested_number_of_bits / 8];
+        let result = if prediction_resistance_request {
+            entry
+                .drbg
+                .fill_bytes_pr(&mut pseudorandom_bits, additional_input)
+        } else {
+            entry
+                .drbg
+                .fill_bytes_with_ai(&mut pseudorandom_bits, additional_input)
+        };
+        match result {
+            Ok(()) => (Status::Success, Some(pseudorandom_bits)),
+            Err(error) => (status(error), None),
+        }
+    }
+
+    /// Section 9.2 Reseed_function.
+    pub fn reseed_function(
+        &mut self,
+        state_handle: StateHandle,
+        prediction_resistance_request: bool,
+        additional_input: &[u8],
+    ) -> Status {
+        // Section 9.2 Step 1
+        let Some(entry) = self.instances.get_mut(&state_handle) else {
+            return Status::ErrorFlag;
+        };
+        // Section 9.2 Step 2
+        if prediction_resistance_request && !entry.prediction_resistance_flag {
+            return Status::ErrorFlag;
+        }
+        entry
+            .drbg
+            .reseed_with_ai(additional_input)
+            .map_or_else(status, |_| Status::Success)
+    }
+
+    /// Section 9.4 Uninstantiate_function.
+    ///
+    /// The internal state is zeroized as it is dropped.
+    pub fn uninstantiate_function(&mut self, state_handle: StateHandle) -> Status {
+        match self.instances.remove(&state_handle) {
+            Some(_) => Status::Success,
+            None => Status::ErrorFlag,
+        }
+    }
+}
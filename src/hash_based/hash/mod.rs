@@ -1,4 +1,8 @@
-use crate::{drbg::variant::DrbgVariant, hash_based::hashfn::HashFn};
+use crate::{
+    drbg::variant::{Approval, DrbgVariant, MechanismName},
+    hash_based::hashfn::HashFn,
+};
+use zeroize::Zeroize;
 
 mod util;
 
@@ -8,6 +12,8 @@ pub struct Hash<F: HashFn> {
 }
 
 impl<F: HashFn> Hash<F> {
+    const MECHANISM_NAME: MechanismName = MechanismName::join(&["Hash_DRBG ", F::NAME]);
+
     fn hashgen(&self, bytes: &mut [u8]) {
         let mut data = self.v.clone();
         for block in bytes.chunks_mut(F::BLOCK_LEN) {
@@ -19,11 +25,21 @@ impl<F: HashFn> Hash<F> {
 }
 
 impl<F: HashFn> DrbgVariant for Hash<F> {
+    const NAME: &'static str = Self::MECHANISM_NAME.as_str();
     const MAX_RESEED_INTERVAL: u64 = 1 << 48;
     const SECURITY_STRENGTH: usize = F::SECURITY_STRENGTH;
+    const OUTPUT_BLOCK_LEN: usize = F::BLOCK_LEN;
+    const APPROVED: Approval = F::APPROVED;
 
     // Section 10.1.1.2
-    fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Self {
+    type Params = ();
+
+    fn instantiate(
+        _: &Self::Params,
+        entropy_input: &[u8],
+        nonce: &[u8],
+        personalization_string: &[u8],
+    ) -> Self {
         let seed_material = [entropy_input, nonce, personalization_string].concat();
         let v = util::hash_df::<F>(&seed_material);
 
@@ -63,4 +79,18 @@ impl<F: HashFn> DrbgVariant for Hash<F> {
         util::add(self.v.as_mut(), self.c.as_ref());
         util::add(self.v.as_mut(), &reseed_counter.to_be_bytes());
     }
+
+    fn zeroize(&mut self) {
+        self.v.as_mut().zeroize();
+        self.c.as_mut().zeroize();
+    }
+
+    fn is_zeroized(&self) -> bool {
+        [self.v.as_ref(), self.c.as_ref()]
+            .concat()
+            .iter()
+            .all(|&byte| byte == 0)
+    }
+
+    const SELF_TEST_KAT: Option<[u8; 32]> = F::HASH_DRBG_KAT;
 }
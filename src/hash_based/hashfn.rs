@@ -1,59 +1,215 @@
-use aes::cipher::{
-    consts::{U55, U111},
-    generic_array::GenericArray,
-};
-use sha2::digest::OutputSizeUser;
+use crate::drbg::{self_test::hex, variant::Approval};
+use hmac::{Mac, SimpleHmac};
+use sha2::digest::{Digest, Output, OutputSizeUser, core_api::BlockSizeUser, typenum::Unsigned};
 
 pub trait HashFn {
+    const NAME: &'static str;
     const BLOCK_LEN: usize;
     const SEED_LEN: usize;
     const SECURITY_STRENGTH: usize;
 
     const MAX_RESEED_INTERVAL: u64 = 1 << 48;
+    // Whether the hash function is approved for Hash_DRBG and HMAC_DRBG.
+    const APPROVED: Approval = Approval(false);
+
+    // Known answers of the Hash_DRBG and HMAC_DRBG self-tests.
+    const HASH_DRBG_KAT: Option<[u8; 32]>;
+    const HMAC_DRBG_KAT: Option<[u8; 32]>;
 
     type Seed: Clone + AsRef<[u8]> + AsMut<[u8]>;
     fn seed_from_slice(slice: &[u8]) -> Self::Seed;
 
-    type Hash: AsRef<[u8]>;
+    type Hash: AsRef<[u8]> + AsMut<[u8]>;
     fn hash_from_slice(slice: &[u8]) -> Self::Hash;
     fn hash(data: impl AsRef<[u8]>) -> Self::Hash;
     fn hmac(key: &Self::Hash, input: &[u8]) -> Self::Hash;
 }
 
+/// Parameter set of a hash function for Hash_DRBG and HMAC_DRBG (Section 10.1 Table 2).
+///
+/// Lengths and the security strength are in bytes. Implement it on a marker type to instantiate
+/// `Hash<MyParams>` and `Hmac<MyParams>` over any RustCrypto digest, the parameters are checked against
+/// the digest's output size when the DRBG is compiled.
+pub trait HashParams {
+    type Digest: Digest + BlockSizeUser;
+
+    /// Hash function name as in the CAVP response files, e.g. `"SHA-512/256"`.
+    const NAME: &'static str;
+    /// seedlen, at least outlen (440 bits for outlen up to 256 bits, 888 bits above in Table 2).
+    const SEED_LEN: usize;
+    /// Highest supported security strength, one of 14, 16, 24 or 32 bytes and at most outlen.
+    const SECURITY_STRENGTH: usize;
+    /// Whether the hash function is approved for Hash_DRBG and HMAC_DRBG (SP 800-131A).
+    ///
+    /// Only this crate's SHA-2 and SHA-3 types are approved, it cannot be set by other crates.
+    const APPROVED: Approval = Approval(false);
+
+    /// Known answers of the Hash_DRBG and HMAC_DRBG self-tests, see `DrbgVariant::SELF_TEST_KAT`.
+    const HASH_DRBG_KAT: Option<[u8; 32]> = None;
+    const HMAC_DRBG_KAT: Option<[u8; 32]> = None;
+}
+
+// Evaluated when a DRBG over the parameter set is compiled, inconsistent parameters fail the build.
+const fn checked_security_strength<P: HashParams>() -> usize {
+    let out_len = <<P::Digest as OutputSizeUser>::OutputSize as Unsigned>::USIZE;
+    assert!(
+        P::SEED_LEN >= out_len,
+        "HashParams::SEED_LEN is shorter than the digest output"
+    );
+    assert!(
+        matches!(P::SECURITY_STRENGTH, 14 | 16 | 24 | 32),
+        "HashParams::SECURITY_STRENGTH is not 14, 16, 24 or 32 bytes"
+    );
+    assert!(
+        P::SECURITY_STRENGTH <= out_len,
+        "HashParams::SECURITY_STRENGTH exceeds the digest output"
+    );
+    P::SECURITY_STRENGTH
+}
+
+impl<P: HashParams> HashFn for P {
+    const NAME: &'static str = P::NAME;
+    const BLOCK_LEN: usize = <<P::Digest as OutputSizeUser>::OutputSize as Unsigned>::USIZE;
+    const SEED_LEN: usize = P::SEED_LEN;
+    const SECURITY_STRENGTH: usize = checked_security_strength::<P>();
+    const APPROVED: Approval = P::APPROVED;
+
+    const HASH_DRBG_KAT: Option<[u8; 32]> = P::HASH_DRBG_KAT;
+    const HMAC_DRBG_KAT: Option<[u8; 32]> = P::HMAC_DRBG_KAT;
+
+    type Seed = Box<[u8]>;
+    fn seed_from_slice(slice: &[u8]) -> Self::Seed {
+        slice.into()
+    }
+
+    type Hash = Output<P::Digest>;
+    fn hash_from_slice(slice: &[u8]) -> Self::Hash {
+        Self::Hash::clone_from_slice(slice)
+    }
+    fn hash(data: impl AsRef<[u8]>) -> Self::Hash {
+        P::Digest::digest(data)
+    }
+    fn hmac(key: &Self::Hash, input: &[u8]) -> Self::Hash {
+        let mut hmac =
+            SimpleHmac::<P::Digest>::new_from_slice(key).expect("HMAC can take key of any size");
+        hmac.update(input);
+        hmac.finalize().into_bytes()
+    }
+}
+
 macro_rules! impl_sha {
-    ($name:ident, $block_len:literal, $seed_len_c:literal, $seed_len:ident, $security_strength:literal) => {
-        impl HashFn for sha2::$name {
-            const BLOCK_LEN: usize = $block_len;
-            const SEED_LEN: usize = $seed_len_c;
+    ($digest:path, $name:literal, $seed_len:literal, $security_strength:literal, $approved:literal, $hash_kat:literal, $hmac_kat:literal) => {
+        impl HashParams for $digest {
+            type Digest = Self;
+
+            const NAME: &'static str = $name;
+            const SEED_LEN: usize = $seed_len;
             const SECURITY_STRENGTH: usize = $security_strength;
+            const APPROVED: Approval = Approval($approved);
 
-            type Seed = GenericArray<u8, $seed_len>;
-            fn seed_from_slice(slice: &[u8]) -> Self::Seed {
-                Self::Seed::clone_from_slice(slice)
-            }
-
-            type Hash = GenericArray<u8, <Self as OutputSizeUser>::OutputSize>;
-            fn hash_from_slice(slice: &[u8]) -> Self::Hash {
-                Self::Hash::clone_from_slice(slice)
-            }
-            fn hash(data: impl AsRef<[u8]>) -> Self::Hash {
-                use sha2::Digest;
-                Self::digest(data)
-            }
-            fn hmac(key: &Self::Hash, input: &[u8]) -> Self::Hash {
-                use hmac::{Hmac, Mac};
-                let mut hmac =
-                    Hmac::<Self>::new_from_slice(key).expect("HMAC can take key of any size");
-                hmac.update(input);
-                Self::hash_from_slice(&hmac.finalize().into_bytes())
-            }
+            const HASH_DRBG_KAT: Option<[u8; 32]> = Some(hex($hash_kat));
+            const HMAC_DRBG_KAT: Option<[u8; 32]> = Some(hex($hmac_kat));
         }
     };
 }
 
-impl_sha!(Sha224, 28, 55, U55, 24);
-impl_sha!(Sha512_224, 28, 55, U55, 24);
-impl_sha!(Sha256, 32, 55, U55, 32);
-impl_sha!(Sha512_256, 32, 55, U55, 32);
-impl_sha!(Sha384, 48, 111, U111, 32);
-impl_sha!(Sha512, 64, 111, U111, 32);
+impl_sha!(
+    sha2::Sha224,
+    "SHA-224",
+    55,
+    24,
+    true,
+    "1a40026385d902aa0c211d5eca676b2c2272e4b35a6b53803371967585783521",
+    "0232b204133802ca9aa8070ba767efe19651b864dfb7b5a1bf534581040e98dc"
+);
+impl_sha!(
+    sha2::Sha512_224,
+    "SHA-512/224",
+    55,
+    24,
+    true,
+    "fa78db92067cefce7948768c0f2cc0ccb5f5f57708ace8590e3baf4b3f389b4f",
+    "e8ce5e7cfbe74d38bc02babec1dab0b92b1f5c75e7977b7ff56969e6ed1c3f46"
+);
+impl_sha!(
+    sha2::Sha256,
+    "SHA-256",
+    55,
+    32,
+    true,
+    "dbc143af52a7c03be67d2aa5a224b97f208b6c975f538850a979dd4297723719",
+    "733f6e38c2d173d7ca806fda4f1da3ab0d9f68dfe98cefa023640f810abe6bc0"
+);
+impl_sha!(
+    sha2::Sha512_256,
+    "SHA-512/256",
+    55,
+    32,
+    true,
+    "817c51cd337533f25c97ed2c93c1a9022afb18dab2a6606f249ea5bbcd9cfc58",
+    "0a7d7132dd9d51fa3521fe77a689d09e4b4f147bb3591698f931736fb4e2cc05"
+);
+impl_sha!(
+    sha2::Sha384,
+    "SHA-384",
+    111,
+    32,
+    true,
+    "0e16adb2d234e6cda32f57fc502ab28ed09a8fafa41d51e377756a2f79d46b3f",
+    "d446340533e390b4248ccd613b44202ca1dea8036fd270a7af8ea8720394e53c"
+);
+impl_sha!(
+    sha2::Sha512,
+    "SHA-512",
+    111,
+    32,
+    true,
+    "4e0d99ead7a9a2dc981cf9ba6845d209ef978b173e919df2e511dc3ec70a07ea",
+    "cbf5f43ce13162cfd8170afe79a123ccd1131c6fbeb9986d99fad2f32469f8d0"
+);
+impl_sha!(
+    sha3::Sha3_224,
+    "SHA3-224",
+    55,
+    24,
+    true,
+    "832f69e42141096ef6b32121da3afffa8cf1379f1199847525a93e2af7d89602",
+    "4a4deaf1c85208dd0ac544f9b749117055fabb447a27ed5ff15472328d6f4d2d"
+);
+impl_sha!(
+    sha3::Sha3_256,
+    "SHA3-256",
+    55,
+    32,
+    true,
+    "5c27c16b72a6ab543b5b99bc0d3c7fe5f5a2e79237a779b3c38067fbe35f75f0",
+    "d1db3278eb79790ddeccdf5f4ea7606e526d128410f10041007b840e6624a2bd"
+);
+impl_sha!(
+    sha3::Sha3_384,
+    "SHA3-384",
+    111,
+    32,
+    true,
+    "03eb2ee562a53c781358ecb13eba98610e609199553f7c6f6d0ab18a54259b4f",
+    "0954469938768a5294ed053dd348067fcbecf40a4a30e0abf9117094849bb4ac"
+);
+impl_sha!(
+    sha3::Sha3_512,
+    "SHA3-512",
+    111,
+    32,
+    true,
+    "feba40ebeb2f81ae7bb0241b5c51de21659b5f2ac1f1335a7d48b86e3c884378",
+    "8d54261fb1e8f173c5246a794cef0d0c0054ed0cd047bba4a328a9884d8e5d4d"
+);
+#[cfg(feature = "legacy-sha1")]
+impl_sha!(
+    sha1::Sha1,
+    "SHA-1",
+    55,
+    16,
+    false,
+    "c93a2f214e1adb92353ed00d387f30345ca2c62a73a7b55516bc7ebde50dfcdb",
+    "a051e5e76f7c79a4df15ceb4e8523391fec22842d17299f96456233dc9d82167"
+);
@@ -1,4 +1,8 @@
-use crate::{drbg::variant::DrbgVariant, hash_based::hashfn::HashFn};
+use crate::{
+    drbg::variant::{Approval, DrbgVariant, MechanismName},
+    hash_based::hashfn::HashFn,
+};
+use zeroize::Zeroize;
 
 pub struct Hmac<F: HashFn> {
     v: F::Hash,
@@ -6,6 +10,8 @@ pub struct Hmac<F: HashFn> {
 }
 
 impl<F: HashFn> Hmac<F> {
+    const MECHANISM_NAME: MechanismName = MechanismName::join(&["HMAC_DRBG ", F::NAME]);
+
     // Section 10.1.2.2
     fn update(&mut self, provided_data: &[u8]) {
         let input = [self.v.as_ref(), &[0x00], provided_data].concat();
@@ -21,11 +27,21 @@ impl<F: HashFn> Hmac<F> {
 }
 
 impl<F: HashFn> DrbgVariant for Hmac<F> {
+    const NAME: &'static str = Self::MECHANISM_NAME.as_str();
     const MAX_RESEED_INTERVAL: u64 = F::MAX_RESEED_INTERVAL;
     const SECURITY_STRENGTH: usize = F::SECURITY_STRENGTH;
+    const OUTPUT_BLOCK_LEN: usize = F::BLOCK_LEN;
+    const APPROVED: Approval = F::APPROVED;
 
     // Section 10.1.2.3
-    fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Self {
+    type Params = ();
+
+    fn instantiate(
+        _: &Self::Params,
+        entropy_input: &[u8],
+        nonce: &[u8],
+        personalization_string: &[u8],
+    ) -> Self {
         let seed_material = [entropy_input, nonce, personalization_string].concat();
         let mut hmac = Self {
             v: F::hash_from_slice(&vec![0x01; F::BLOCK_LEN]),
@@ -52,4 +68,18 @@ impl<F: HashFn> DrbgVariant for Hmac<F> {
         }
         self.update(additional_input);
     }
+
+    fn zeroize(&mut self) {
+        self.v.as_mut().zeroize();
+        self.key.as_mut().zeroize();
+    }
+
+    fn is_zeroized(&self) -> bool {
+        [self.v.as_ref(), self.key.as_ref()]
+            .concat()
+            .iter()
+            .all(|&byte| byte == 0)
+    }
+
+    const SELF_TEST_KAT: Option<[u8; 32]> = F::HMAC_DRBG_KAT;
 }
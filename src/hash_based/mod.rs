@@ -3,4 +3,5 @@ mod hashfn;
 mod hmac;
 
 pub use hash::Hash;
+pub use hashfn::HashParams;
 pub use hmac::Hmac;
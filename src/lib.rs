@@ -1,121 +1,186 @@
-use ctr::{Aes128, Aes192, Aes256, Ctr};
-use drbg::{Drbg, variant::DrbgVariant};
-use hash_based::{Hash, Hmac};
-use pr::{NoPr, Pr};
 use rand_core::{OsRng, TryCryptoRng, TryRngCore};
+#[cfg(feature = "legacy-sha1")]
+use sha1::Sha1;
 use sha2::{Sha224, Sha256, Sha384, Sha512, Sha512_224, Sha512_256};
+use sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+use std::time::Duration;
 
+mod any;
+mod auto;
 mod ctr;
 mod drbg;
 mod entropy;
+pub mod functional;
 mod hash_based;
 mod pr;
 
-pub use drbg::DrbgError;
-pub use entropy::{CryptoEntropy, Entropy};
+pub use any::AnyDrbg;
+pub use auto::AutoDrbg;
+#[cfg(feature = "legacy-tdea")]
+pub use ctr::Tdea3;
+pub use ctr::{Aes128, Aes192, Aes256, Cipher, Ctr, CtrNoDf};
+#[cfg(feature = "camellia")]
+pub use ctr::{Camellia128, Camellia192, Camellia256};
+pub use drbg::{
+    Drbg, DrbgBuilder, DrbgError, ErrorPolicy, RandomBitGenerator, RequestMode,
+    variant::DrbgVariant,
+};
+pub use entropy::{Approved, CryptoEntropy, Entropy, NoSource, NoSourceError};
+pub use hash_based::{Hash, HashParams, Hmac};
+pub use pr::{NoPr, Pr, PredictionResistance};
 
 // Only allow the user to change the reseed interval if they are using a NoPr variant.
 // When Pr is enabled, the reseed interval need not be changed, we reseed after every call to generate.
 macro_rules! define_reseed_interval {
     ($builder:ident, NoPr) => {
         impl<'a, E> $builder<'a, E> {
-            pub fn reseed_interval(mut self, reseed_interval: u64) -> Self {
-                self.reseed_interval = Some(reseed_interval);
-                self
+            pub fn reseed_interval(self, reseed_interval: u64) -> Self {
+                Self(self.0.reseed_interval(reseed_interval))
             }
         }
     };
     ($builder:ident, Pr) => {};
 }
 
+// Only CTR_DRBG has a configurable counter length.
+macro_rules! define_ctr_len {
+    ($builder:ident, Ctr) => {
+        define_ctr_len!(@impl $builder);
+    };
+    ($builder:ident, CtrNoDf) => {
+        define_ctr_len!(@impl $builder);
+    };
+    ($builder:ident, $variant:ident) => {};
+    (@impl $builder:ident) => {
+        impl<'a, E> $builder<'a, E> {
+            /// Number of rightmost bits of V used as the counter (Section 10.2.1).
+            ///
+            /// Must satisfy 4 <= ctr_len <= blocklen, defaults to blocklen.
+            /// Counters shorter than blocklen also reduce the maximum number of bytes per request.
+            pub fn ctr_len(self, ctr_len: usize) -> Self {
+                Self(self.0.ctr_len(ctr_len))
+            }
+        }
+    };
+}
+
+// Only NoPr variants need a per-request prediction resistance option, Pr variants already reseed on every request.
+macro_rules! define_fill_bytes_pr {
+    ($name:ident, NoPr) => {
+        impl<E: Entropy> $name<E> {
+            /// Fill bytes array with random bits, reseeding from the entropy source first.
+            ///
+            /// Gives prediction resistance for this request only, the output matches the Pr variant of this DRBG.
+            /// `additional_input` will be factored into the reseed.
+            pub fn fill_bytes_pr(
+                &mut self,
+                bytes: &mut [u8],
+                additional_input: &[u8],
+            ) -> Result<(), DrbgError<E::Error>> {
+                self.0.fill_bytes_pr(bytes, additional_input)
+            }
+        }
+    };
+    ($name:ident, Pr) => {};
+}
+
 macro_rules! define_drbg_builder {
     ($name:ident, $builder:ident, $pr:tt, $variant:ident, $inner:ident) => {
-        pub struct $builder<'a, E> {
-            personalization_string: &'a [u8],
-            reseed_interval: Option<u64>,
-            nonce: Option<&'a [u8]>,
-            entropy: E,
-        }
+        pub struct $builder<'a, E>(DrbgBuilder<'a, $pr, $variant<$inner>, E>);
 
         impl<'a, E> $builder<'a, E> {
-            pub fn personalization_string(mut self, personalization_string: &'a [u8]) -> Self {
-                self.personalization_string = personalization_string;
-                self
+            pub fn personalization_string(self, personalization_string: &'a [u8]) -> Self {
+                Self(self.0.personalization_string(personalization_string))
+            }
+
+            pub fn nonce(self, nonce: &'a [u8]) -> Self {
+                Self(self.0.nonce(nonce))
+            }
+
+            /// Requested instantiation security strength in bits (Section 9.1).
+            ///
+            /// Rounded up to the nearest of 112, 128, 192 or 256 bits and cannot exceed the mechanism's security strength.
+            /// A lower security strength reduces the amount of entropy and nonce gathered by the DRBG.
+            pub fn security_strength(self, security_strength: usize) -> Self {
+                Self(self.0.security_strength(security_strength))
+            }
+
+            /// Number of entropy input bytes gathered on instantiation and every reseed.
+            ///
+            /// Defaults to the minimum entropy for the security strength (seedlen for CTR DRBGs without df),
+            /// over-sampled by the source's `Entropy::MIN_ENTROPY_PER_BIT`. Shorter entropy inputs are rejected.
+            pub fn entropy_input_len(self, entropy_input_len: usize) -> Self {
+                Self(self.0.entropy_input_len(entropy_input_len))
+            }
+
+            /// How long to keep retrying entropy reads that return no bytes, see `Entropy::fill_partial`.
+            ///
+            /// Partial reads are always accumulated. Defaults to no waiting, an empty read fails with `DrbgError::EntropyTimeout`.
+            pub fn entropy_timeout(self, entropy_timeout: Duration) -> Self {
+                Self(self.0.entropy_timeout(entropy_timeout))
+            }
+
+            /// How requests larger than max_number_of_bits_per_request are handled, see `RequestMode`.
+            ///
+            /// Defaults to `RequestMode::Chunked`.
+            pub fn request_mode(self, request_mode: RequestMode) -> Self {
+                Self(self.0.request_mode(request_mode))
             }
 
-            pub fn nonce(mut self, nonce: &'a [u8]) -> Self {
-                self.nonce = Some(nonce);
-                self
+            /// What happens after an entropy source failure, see `ErrorPolicy`.
+            ///
+            /// Defaults to `ErrorPolicy::Transient`.
+            pub fn error_policy(self, error_policy: ErrorPolicy) -> Self {
+                Self(self.0.error_policy(error_policy))
+            }
+
+            /// Run the mechanism's known-answer self-test before its first instantiation in this process.
+            ///
+            /// A failure returns `DrbgError::SelfTestFailed` and puts every DRBG into the error state.
+            pub fn self_test(self, self_test: bool) -> Self {
+                Self(self.0.self_test(self_test))
+            }
+
+            /// Compare every generated block with the previous one (blocklen for CTR, outlen for Hash and HMAC).
+            ///
+            /// A repeated block returns `DrbgError::ContinuousTestFailed` and puts the DRBG into the error state.
+            /// The first block after instantiation is generated and discarded.
+            pub fn continuous_test(self, continuous_test: bool) -> Self {
+                Self(self.0.continuous_test(continuous_test))
             }
 
             pub fn entropy<E2: Entropy>(self, entropy: E2) -> $builder<'a, E2> {
-                $builder {
-                    personalization_string: self.personalization_string,
-                    reseed_interval: self.reseed_interval,
-                    nonce: self.nonce,
-                    entropy,
-                }
+                $builder(self.0.entropy(entropy))
             }
         }
 
         impl<'a, E: Entropy> $builder<'a, E> {
-            pub fn build(mut self) -> Result<$name<E>, DrbgError<E::Error>> {
-                // Section 9.1 Step 3
-                if self.personalization_string.len()
-                    > <$variant<$inner> as DrbgVariant>::MAX_PERSONALIZATION_STRING_LENGTH
-                {
-                    return Err(DrbgError::PersonalizationStringTooLong);
-                }
+            /// Whether `build` would produce a DRBG in an approved (FIPS) configuration.
+            ///
+            /// Checks the mechanism, security strength, reseed interval, entropy source and nonce length.
+            pub fn is_approved(&self) -> bool {
+                self.0.is_approved()
+            }
 
-                // Section 9.1 Step 8
-                let mut drbg = match self.nonce {
-                    // We assume that if the caller provided a nonce, it is acceptable (aside from length checks).
-                    Some(nonce) => {
-                        if nonce.len() < <$variant<$inner> as DrbgVariant>::SECURITY_STRENGTH / 2 {
-                            return Err(DrbgError::NonceTooShort);
-                        } else if nonce.len() > <$variant<$inner> as DrbgVariant>::MAX_ENTROPY {
-                            return Err(DrbgError::NonceTooLong);
-                        }
-
-                        Drbg::<$pr, $variant<$inner>, E>::new(
-                            self.entropy,
-                            nonce,
-                            self.personalization_string,
-                        )?
-                    }
-                    // Section 8.6.7
-                    // Otherwise, we generate a nonce using our entropy source with half security strength length.
-                    None => {
-                        let mut nonce =
-                            vec![0; <$variant<$inner> as DrbgVariant>::SECURITY_STRENGTH / 2];
-                        self.entropy
-                            .fill_bytes(&mut nonce)
-                            .map_err(DrbgError::EntropyError)?;
-                        Drbg::<$pr, $variant<$inner>, E>::new(
-                            self.entropy,
-                            &nonce,
-                            self.personalization_string,
-                        )?
-                    }
-                };
-
-                if let Some(reseed_interval) = self.reseed_interval {
-                    if reseed_interval < 1 {
-                        return Err(DrbgError::ReseedIntervalTooShort);
-                    } else if reseed_interval
-                        > <$variant<$inner> as DrbgVariant>::MAX_RESEED_INTERVAL
-                    {
-                        return Err(DrbgError::ReseedIntervalTooLong);
-                    }
-
-                    drbg.set_reseed_interval(reseed_interval);
-                }
+            pub fn build(self) -> Result<$name<E>, DrbgError<E::Error>> {
+                self.0.build().map($name)
+            }
 
-                Ok($name(drbg))
+            /// Build the DRBG from caller-supplied entropy input instead of reading it from the entropy source.
+            ///
+            /// The entropy input must hold at least the minimum entropy for the security strength and at most MAX_ENTROPY bytes
+            /// (exactly seedlen for CTR DRBGs without df). The source is still used for a generated nonce and for later reseeds,
+            /// use `NoSource` to only ever reseed with `reseed_with_entropy_input`.
+            pub fn build_with_entropy_input(
+                self,
+                entropy_input: &[u8],
+            ) -> Result<$name<E>, DrbgError<E::Error>> {
+                self.0.build_with_entropy_input(entropy_input).map($name)
             }
         }
 
         define_reseed_interval!($builder, $pr);
+        define_ctr_len!($builder, $variant);
     };
 }
 
@@ -132,6 +197,12 @@ macro_rules! define_drbg {
         /// DrbgPrCtrAes192
         /// DrbgCtrAes256
         /// DrbgPrCtrAes256
+        /// DrbgCtrNoDfAes128
+        /// DrbgPrCtrNoDfAes128
+        /// DrbgCtrNoDfAes192
+        /// DrbgPrCtrNoDfAes192
+        /// DrbgCtrNoDfAes256
+        /// DrbgPrCtrNoDfAes256
         /// DrbgHashSha224
         /// DrbgPrHashSha224
         /// DrbgHashSha512_224
@@ -144,6 +215,14 @@ macro_rules! define_drbg {
         /// DrbgPrHashSha384
         /// DrbgHashSha512
         /// DrbgPrHashSha512
+        /// DrbgHashSha3_224
+        /// DrbgPrHashSha3_224
+        /// DrbgHashSha3_256
+        /// DrbgPrHashSha3_256
+        /// DrbgHashSha3_384
+        /// DrbgPrHashSha3_384
+        /// DrbgHashSha3_512
+        /// DrbgPrHashSha3_512
         /// DrbgHmacSha224
         /// DrbgPrHmacSha224
         /// DrbgHmacSha512_224
@@ -156,6 +235,49 @@ macro_rules! define_drbg {
         /// DrbgPrHmacSha384
         /// DrbgHmacSha512
         /// DrbgPrHmacSha512
+        /// DrbgHmacSha3_224
+        /// DrbgPrHmacSha3_224
+        /// DrbgHmacSha3_256
+        /// DrbgPrHmacSha3_256
+        /// DrbgHmacSha3_384
+        /// DrbgPrHmacSha3_384
+        /// DrbgHmacSha3_512
+        /// DrbgPrHmacSha3_512
+        /// ```
+        ///
+        /// With the `legacy-sha1` feature:
+        ///
+        /// ```ignore
+        /// DrbgHashSha1
+        /// DrbgPrHashSha1
+        /// DrbgHmacSha1
+        /// DrbgPrHmacSha1
+        /// ```
+        ///
+        /// With the `legacy-tdea` feature:
+        ///
+        /// ```ignore
+        /// DrbgCtrTdea3
+        /// DrbgPrCtrTdea3
+        /// DrbgCtrNoDfTdea3
+        /// DrbgPrCtrNoDfTdea3
+        /// ```
+        ///
+        /// With the `camellia` feature:
+        ///
+        /// ```ignore
+        /// DrbgCtrCamellia128
+        /// DrbgPrCtrCamellia128
+        /// DrbgCtrCamellia192
+        /// DrbgPrCtrCamellia192
+        /// DrbgCtrCamellia256
+        /// DrbgPrCtrCamellia256
+        /// DrbgCtrNoDfCamellia128
+        /// DrbgPrCtrNoDfCamellia128
+        /// DrbgCtrNoDfCamellia192
+        /// DrbgPrCtrNoDfCamellia192
+        /// DrbgCtrNoDfCamellia256
+        /// DrbgPrCtrNoDfCamellia256
         /// ```
         pub struct $name<E = OsRng>(Drbg<$pr, $variant<$inner>, E>);
 
@@ -181,16 +303,14 @@ macro_rules! define_drbg {
             /// }
             /// ```
             pub fn builder() -> $builder<'a, OsRng> {
-                $builder {
-                    personalization_string: &[],
-                    reseed_interval: None,
-                    nonce: None,
-                    entropy: OsRng,
-                }
+                $builder(Drbg::builder())
             }
         }
 
         impl<E: Entropy> $name<E> {
+            /// Mechanism name as in the CAVP response files, see `RandomBitGenerator::mechanism`.
+            pub const MECHANISM: &'static str = <$variant<$inner> as DrbgVariant>::NAME;
+
             /// Fill bytes array with random bits.
             ///
             /// If you want to include additional input, use the `fill_bytes_with_ai` method.
@@ -206,7 +326,63 @@ macro_rules! define_drbg {
                 bytes: &mut [u8],
                 additional_input: &[u8],
             ) -> Result<(), DrbgError<E::Error>> {
-                self.0.fill_bytes(bytes, additional_input)
+                self.0.fill_bytes_with_ai(bytes, additional_input)
+            }
+
+            /// Reseed the DRBG from its entropy source.
+            ///
+            /// If you want to include additional input, use the `reseed_with_ai` method.
+            pub fn reseed(&mut self) -> Result<(), DrbgError<E::Error>> {
+                self.reseed_with_ai(&[])
+            }
+
+            /// Reseed the DRBG from its entropy source.
+            ///
+            /// `additional_input` will be factored into the new state.
+            /// Fails with `AdditionalInputTooLong` or `EntropyError`, in which case the state is left unchanged.
+            pub fn reseed_with_ai(&mut self, additional_input: &[u8]) -> Result<(), DrbgError<E::Error>> {
+                self.0.reseed_with_ai(additional_input)
+            }
+
+            /// Reseed the DRBG from caller-supplied entropy input instead of reading it from the entropy source.
+            ///
+            /// The entropy input must hold at least the minimum entropy for the security strength and at most MAX_ENTROPY bytes.
+            /// `additional_input` will be factored into the new state.
+            pub fn reseed_with_entropy_input(
+                &mut self,
+                entropy_input: &[u8],
+                additional_input: &[u8],
+            ) -> Result<(), DrbgError<E::Error>> {
+                self.0.reseed_with_entropy_input(entropy_input, additional_input)
+            }
+
+            /// Whether this DRBG runs in an approved (FIPS) configuration.
+            ///
+            /// Checks the mechanism, security strength, reseed interval, entropy source and nonce length.
+            pub fn is_approved(&self) -> bool {
+                self.0.is_approved()
+            }
+
+            /// Whether the DRBG refuses requests because it entered the error state.
+            pub fn is_in_error_state(&self) -> bool {
+                self.0.is_in_error_state()
+            }
+
+            /// Run the mechanism's known-answer self-test on demand.
+            ///
+            /// A failure returns `DrbgError::SelfTestFailed` and puts every DRBG into the error state.
+            pub fn self_test(&mut self) -> Result<(), DrbgError<E::Error>> {
+                self.0.self_test()
+            }
+
+            /// Uninstantiate the DRBG, zeroizing its internal state, and hand back the entropy source.
+            pub fn uninstantiate(self) -> E {
+                self.0.uninstantiate()
+            }
+
+            /// Security strength in bits this DRBG was instantiated at.
+            pub fn security_strength(&self) -> usize {
+                self.0.security_strength()
             }
         }
 
@@ -248,101 +424,432 @@ macro_rules! define_drbg {
 
         impl<E: CryptoEntropy> TryCryptoRng for $name<E> {}
 
+        impl<E: Entropy> RandomBitGenerator for $name<E> {
+            type Error = DrbgError<E::Error>;
+
+            fn fill_bytes_with_ai(
+                &mut self,
+                bytes: &mut [u8],
+                additional_input: &[u8],
+            ) -> Result<(), Self::Error> {
+                $name::fill_bytes_with_ai(self, bytes, additional_input)
+            }
+
+            fn reseed_with_ai(&mut self, additional_input: &[u8]) -> Result<(), Self::Error> {
+                $name::reseed_with_ai(self, additional_input)
+            }
+
+            fn security_strength(&self) -> usize {
+                $name::security_strength(self)
+            }
+
+            fn mechanism(&self) -> &'static str {
+                Self::MECHANISM
+            }
+
+            fn prediction_resistance(&self) -> bool {
+                <$pr as PredictionResistance>::IS_PR
+            }
+        }
+
+        define_fill_bytes_pr!($name, $pr);
         define_drbg_builder!($name, $builder, $pr, $variant, $inner);
     };
 }
 
 macro_rules! define_all_drbg {
-    ($(($name:ident, $builder:ident, $pr:tt, $variant:ident, $inner:ident)),*$(,)?) => {
+    ($($(#[$attr:meta])* ($mechanism:ident, $pr_mechanism:ident, $name:ident, $builder:ident, $pr_name:ident, $pr_builder:ident, $variant:ident, $inner:ident)),*$(,)?) => {
         $(
-            define_drbg!(
-                $name,
-                $builder,
-                $pr,
-                $variant,
-                $inner
-            );
+            $(#[$attr])* define_drbg!($name, $builder, NoPr, $variant, $inner);
+            $(#[$attr])* define_drbg!($pr_name, $pr_builder, Pr, $variant, $inner);
         )*
     };
 }
 
-define_all_drbg!(
-    (DrbgCtrAes128, DrbgCtrAes128Builder, NoPr, Ctr, Aes128),
-    (DrbgPrCtrAes128, DrbgPrCtrAes128Builder, Pr, Ctr, Aes128),
-    (DrbgCtrAes192, DrbgCtrAes192Builder, NoPr, Ctr, Aes192),
-    (DrbgPrCtrAes192, DrbgPrCtrAes192Builder, Pr, Ctr, Aes192),
-    (DrbgCtrAes256, DrbgCtrAes256Builder, NoPr, Ctr, Aes256),
-    (DrbgPrCtrAes256, DrbgPrCtrAes256Builder, Pr, Ctr, Aes256),
-    (DrbgHashSha224, DrbgHashSha224Builder, NoPr, Hash, Sha224),
-    (DrbgPrHashSha224, DrbgPrHashSha224Builder, Pr, Hash, Sha224),
-    (
-        DrbgHashSha512_224,
-        DrbgHashSha512_224Builder,
-        NoPr,
-        Hash,
-        Sha512_224
-    ),
-    (
-        DrbgPrHashSha512_224,
-        DrbgPrHashSha512_224Builder,
-        Pr,
-        Hash,
-        Sha512_224
-    ),
-    (DrbgHashSha256, DrbgHashSha256Builder, NoPr, Hash, Sha256),
-    (DrbgPrHashSha256, DrbgPrHashSha256Builder, Pr, Hash, Sha256),
-    (
-        DrbgHashSha512_256,
-        DrbgHashSha512_256Builder,
-        NoPr,
-        Hash,
-        Sha512_256
-    ),
-    (
-        DrbgPrHashSha512_256,
-        DrbgPrHashSha512_256Builder,
-        Pr,
-        Hash,
-        Sha512_256
-    ),
-    (DrbgHashSha384, DrbgHashSha384Builder, NoPr, Hash, Sha384),
-    (DrbgPrHashSha384, DrbgPrHashSha384Builder, Pr, Hash, Sha384),
-    (DrbgHashSha512, DrbgHashSha512Builder, NoPr, Hash, Sha512),
-    (DrbgPrHashSha512, DrbgPrHashSha512Builder, Pr, Hash, Sha512),
-    (DrbgHmacSha224, DrbgHmacSha224Builder, NoPr, Hmac, Sha224),
-    (DrbgPrHmacSha224, DrbgPrHmacSha224Builder, Pr, Hmac, Sha224),
-    (
-        DrbgHmacSha512_224,
-        DrbgHmacSha512_224Builder,
-        NoPr,
-        Hmac,
-        Sha512_224
-    ),
-    (
-        DrbgPrHmacSha512_224,
-        DrbgPrHmacSha512_224Builder,
-        Pr,
-        Hmac,
-        Sha512_224
-    ),
-    (DrbgHmacSha256, DrbgHmacSha256Builder, NoPr, Hmac, Sha256),
-    (DrbgPrHmacSha256, DrbgPrHmacSha256Builder, Pr, Hmac, Sha256),
-    (
-        DrbgHmacSha512_256,
-        DrbgHmacSha512_256Builder,
-        NoPr,
-        Hmac,
-        Sha512_256
-    ),
-    (
-        DrbgPrHmacSha512_256,
-        DrbgPrHmacSha512_256Builder,
-        Pr,
-        Hmac,
-        Sha512_256
-    ),
-    (DrbgHmacSha384, DrbgHmacSha384Builder, NoPr, Hmac, Sha384),
-    (DrbgPrHmacSha384, DrbgPrHmacSha384Builder, Pr, Hmac, Sha384),
-    (DrbgHmacSha512, DrbgHmacSha512Builder, NoPr, Hmac, Sha512),
-    (DrbgPrHmacSha512, DrbgPrHmacSha512Builder, Pr, Hmac, Sha512),
-);
+// Every mechanism once, as (AnyDrbg variant, prediction resistant AnyDrbg variant, type, builder,
+// prediction resistant type, prediction resistant builder, DRBG variant, inner primitive).
+// Expands `$callback!` over the whole list, which keeps the DRBG types, AnyDrbg and the functional
+// interface in sync.
+macro_rules! for_each_mechanism {
+    ($callback:ident) => {
+        $callback!(
+            (
+                CtrAes128,
+                PrCtrAes128,
+                DrbgCtrAes128,
+                DrbgCtrAes128Builder,
+                DrbgPrCtrAes128,
+                DrbgPrCtrAes128Builder,
+                Ctr,
+                Aes128
+            ),
+            (
+                CtrAes192,
+                PrCtrAes192,
+                DrbgCtrAes192,
+                DrbgCtrAes192Builder,
+                DrbgPrCtrAes192,
+                DrbgPrCtrAes192Builder,
+                Ctr,
+                Aes192
+            ),
+            (
+                CtrAes256,
+                PrCtrAes256,
+                DrbgCtrAes256,
+                DrbgCtrAes256Builder,
+                DrbgPrCtrAes256,
+                DrbgPrCtrAes256Builder,
+                Ctr,
+                Aes256
+            ),
+            (
+                CtrNoDfAes128,
+                PrCtrNoDfAes128,
+                DrbgCtrNoDfAes128,
+                DrbgCtrNoDfAes128Builder,
+                DrbgPrCtrNoDfAes128,
+                DrbgPrCtrNoDfAes128Builder,
+                CtrNoDf,
+                Aes128
+            ),
+            (
+                CtrNoDfAes192,
+                PrCtrNoDfAes192,
+                DrbgCtrNoDfAes192,
+                DrbgCtrNoDfAes192Builder,
+                DrbgPrCtrNoDfAes192,
+                DrbgPrCtrNoDfAes192Builder,
+                CtrNoDf,
+                Aes192
+            ),
+            (
+                CtrNoDfAes256,
+                PrCtrNoDfAes256,
+                DrbgCtrNoDfAes256,
+                DrbgCtrNoDfAes256Builder,
+                DrbgPrCtrNoDfAes256,
+                DrbgPrCtrNoDfAes256Builder,
+                CtrNoDf,
+                Aes256
+            ),
+            (
+                HashSha224,
+                PrHashSha224,
+                DrbgHashSha224,
+                DrbgHashSha224Builder,
+                DrbgPrHashSha224,
+                DrbgPrHashSha224Builder,
+                Hash,
+                Sha224
+            ),
+            (
+                HashSha512_224,
+                PrHashSha512_224,
+                DrbgHashSha512_224,
+                DrbgHashSha512_224Builder,
+                DrbgPrHashSha512_224,
+                DrbgPrHashSha512_224Builder,
+                Hash,
+                Sha512_224
+            ),
+            (
+                HashSha256,
+                PrHashSha256,
+                DrbgHashSha256,
+                DrbgHashSha256Builder,
+                DrbgPrHashSha256,
+                DrbgPrHashSha256Builder,
+                Hash,
+                Sha256
+            ),
+            (
+                HashSha512_256,
+                PrHashSha512_256,
+                DrbgHashSha512_256,
+                DrbgHashSha512_256Builder,
+                DrbgPrHashSha512_256,
+                DrbgPrHashSha512_256Builder,
+                Hash,
+                Sha512_256
+            ),
+            (
+                HashSha384,
+                PrHashSha384,
+                DrbgHashSha384,
+                DrbgHashSha384Builder,
+                DrbgPrHashSha384,
+                DrbgPrHashSha384Builder,
+                Hash,
+                Sha384
+            ),
+            (
+                HashSha512,
+                PrHashSha512,
+                DrbgHashSha512,
+                DrbgHashSha512Builder,
+                DrbgPrHashSha512,
+                DrbgPrHashSha512Builder,
+                Hash,
+                Sha512
+            ),
+            (
+                HashSha3_224,
+                PrHashSha3_224,
+                DrbgHashSha3_224,
+                DrbgHashSha3_224Builder,
+                DrbgPrHashSha3_224,
+                DrbgPrHashSha3_224Builder,
+                Hash,
+                Sha3_224
+            ),
+            (
+                HashSha3_256,
+                PrHashSha3_256,
+                DrbgHashSha3_256,
+                DrbgHashSha3_256Builder,
+                DrbgPrHashSha3_256,
+                DrbgPrHashSha3_256Builder,
+                Hash,
+                Sha3_256
+            ),
+            (
+                HashSha3_384,
+                PrHashSha3_384,
+                DrbgHashSha3_384,
+                DrbgHashSha3_384Builder,
+                DrbgPrHashSha3_384,
+                DrbgPrHashSha3_384Builder,
+                Hash,
+                Sha3_384
+            ),
+            (
+                HashSha3_512,
+                PrHashSha3_512,
+                DrbgHashSha3_512,
+                DrbgHashSha3_512Builder,
+                DrbgPrHashSha3_512,
+                DrbgPrHashSha3_512Builder,
+                Hash,
+                Sha3_512
+            ),
+            (
+                HmacSha224,
+                PrHmacSha224,
+                DrbgHmacSha224,
+                DrbgHmacSha224Builder,
+                DrbgPrHmacSha224,
+                DrbgPrHmacSha224Builder,
+                Hmac,
+                Sha224
+            ),
+            (
+                HmacSha512_224,
+                PrHmacSha512_224,
+                DrbgHmacSha512_224,
+                DrbgHmacSha512_224Builder,
+                DrbgPrHmacSha512_224,
+                DrbgPrHmacSha512_224Builder,
+                Hmac,
+                Sha512_224
+            ),
+            (
+                HmacSha256,
+                PrHmacSha256,
+                DrbgHmacSha256,
+                DrbgHmacSha256Builder,
+                DrbgPrHmacSha256,
+                DrbgPrHmacSha256Builder,
+                Hmac,
+                Sha256
+            ),
+            (
+                HmacSha512_256,
+                PrHmacSha512_256,
+                DrbgHmacSha512_256,
+                DrbgHmacSha512_256Builder,
+                DrbgPrHmacSha512_256,
+                DrbgPrHmacSha512_256Builder,
+                Hmac,
+                Sha512_256
+            ),
+            (
+                HmacSha384,
+                PrHmacSha384,
+                DrbgHmacSha384,
+                DrbgHmacSha384Builder,
+                DrbgPrHmacSha384,
+                DrbgPrHmacSha384Builder,
+                Hmac,
+                Sha384
+            ),
+            (
+                HmacSha512,
+                PrHmacSha512,
+                DrbgHmacSha512,
+                DrbgHmacSha512Builder,
+                DrbgPrHmacSha512,
+                DrbgPrHmacSha512Builder,
+                Hmac,
+                Sha512
+            ),
+            (
+                HmacSha3_224,
+                PrHmacSha3_224,
+                DrbgHmacSha3_224,
+                DrbgHmacSha3_224Builder,
+                DrbgPrHmacSha3_224,
+                DrbgPrHmacSha3_224Builder,
+                Hmac,
+                Sha3_224
+            ),
+            (
+                HmacSha3_256,
+                PrHmacSha3_256,
+                DrbgHmacSha3_256,
+                DrbgHmacSha3_256Builder,
+                DrbgPrHmacSha3_256,
+                DrbgPrHmacSha3_256Builder,
+                Hmac,
+                Sha3_256
+            ),
+            (
+                HmacSha3_384,
+                PrHmacSha3_384,
+                DrbgHmacSha3_384,
+                DrbgHmacSha3_384Builder,
+                DrbgPrHmacSha3_384,
+                DrbgPrHmacSha3_384Builder,
+                Hmac,
+                Sha3_384
+            ),
+            (
+                HmacSha3_512,
+                PrHmacSha3_512,
+                DrbgHmacSha3_512,
+                DrbgHmacSha3_512Builder,
+                DrbgPrHmacSha3_512,
+                DrbgPrHmacSha3_512Builder,
+                Hmac,
+                Sha3_512
+            ),
+            // SHA-1 is only kept around for comparison testing against legacy implementations.
+            #[cfg(feature = "legacy-sha1")]
+            (
+                HashSha1,
+                PrHashSha1,
+                DrbgHashSha1,
+                DrbgHashSha1Builder,
+                DrbgPrHashSha1,
+                DrbgPrHashSha1Builder,
+                Hash,
+                Sha1
+            ),
+            #[cfg(feature = "legacy-sha1")]
+            (
+                HmacSha1,
+                PrHmacSha1,
+                DrbgHmacSha1,
+                DrbgHmacSha1Builder,
+                DrbgPrHmacSha1,
+                DrbgPrHmacSha1Builder,
+                Hmac,
+                Sha1
+            ),
+            // Three-key TDEA is only kept around for interoperability with legacy implementations.
+            #[cfg(feature = "legacy-tdea")]
+            (
+                CtrTdea3,
+                PrCtrTdea3,
+                DrbgCtrTdea3,
+                DrbgCtrTdea3Builder,
+                DrbgPrCtrTdea3,
+                DrbgPrCtrTdea3Builder,
+                Ctr,
+                Tdea3
+            ),
+            #[cfg(feature = "legacy-tdea")]
+            (
+                CtrNoDfTdea3,
+                PrCtrNoDfTdea3,
+                DrbgCtrNoDfTdea3,
+                DrbgCtrNoDfTdea3Builder,
+                DrbgPrCtrNoDfTdea3,
+                DrbgPrCtrNoDfTdea3Builder,
+                CtrNoDf,
+                Tdea3
+            ),
+            // Camellia CTR_DRBGs for deployments that require it, not approved by SP 800-90A.
+            #[cfg(feature = "camellia")]
+            (
+                CtrCamellia128,
+                PrCtrCamellia128,
+                DrbgCtrCamellia128,
+                DrbgCtrCamellia128Builder,
+                DrbgPrCtrCamellia128,
+                DrbgPrCtrCamellia128Builder,
+                Ctr,
+                Camellia128
+            ),
+            #[cfg(feature = "camellia")]
+            (
+                CtrCamellia192,
+                PrCtrCamellia192,
+                DrbgCtrCamellia192,
+                DrbgCtrCamellia192Builder,
+                DrbgPrCtrCamellia192,
+                DrbgPrCtrCamellia192Builder,
+                Ctr,
+                Camellia192
+            ),
+            #[cfg(feature = "camellia")]
+            (
+                CtrCamellia256,
+                PrCtrCamellia256,
+                DrbgCtrCamellia256,
+                DrbgCtrCamellia256Builder,
+                DrbgPrCtrCamellia256,
+                DrbgPrCtrCamellia256Builder,
+                Ctr,
+                Camellia256
+            ),
+            #[cfg(feature = "camellia")]
+            (
+                CtrNoDfCamellia128,
+                PrCtrNoDfCamellia128,
+                DrbgCtrNoDfCamellia128,
+                DrbgCtrNoDfCamellia128Builder,
+                DrbgPrCtrNoDfCamellia128,
+                DrbgPrCtrNoDfCamellia128Builder,
+                CtrNoDf,
+                Camellia128
+            ),
+            #[cfg(feature = "camellia")]
+            (
+                CtrNoDfCamellia192,
+                PrCtrNoDfCamellia192,
+                DrbgCtrNoDfCamellia192,
+                DrbgCtrNoDfCamellia192Builder,
+                DrbgPrCtrNoDfCamellia192,
+                DrbgPrCtrNoDfCamellia192Builder,
+                CtrNoDf,
+                Camellia192
+            ),
+            #[cfg(feature = "camellia")]
+            (
+                CtrNoDfCamellia256,
+                PrCtrNoDfCamellia256,
+                DrbgCtrNoDfCamellia256,
+                DrbgCtrNoDfCamellia256Builder,
+                DrbgPrCtrNoDfCamellia256,
+                DrbgPrCtrNoDfCamellia256Builder,
+                CtrNoDf,
+                Camellia256
+            ),
+        );
+    };
+}
+
+pub(crate) use for_each_mechanism;
+
+for_each_mechanism!(define_all_drbg);
@@ -1,8 +1,20 @@
+/// Prediction resistance on every request.
 pub struct Pr;
 
+/// Prediction resistance only on request, see `Drbg::fill_bytes_pr`.
 pub struct NoPr;
 
-pub trait PredictionResistance {
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::Pr {}
+    impl Sealed for super::NoPr {}
+}
+
+/// Whether a `Drbg` reseeds before every request (`Pr`) or only when its reseed interval is reached (`NoPr`).
+///
+/// Sealed, `Pr` and `NoPr` are the only implementations.
+pub trait PredictionResistance: sealed::Sealed {
     const IS_PR: bool;
 }
 
@@ -0,0 +1,53 @@
+// Entropy sources shared by the integration tests.
+//
+// Both are deterministic and marked approved, so the tests also run with the fips feature.
+#![allow(dead_code)]
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use kondrbg::{Approved, CryptoEntropy, Entropy};
+
+// Records the length of every entropy request, across clones.
+#[derive(Clone, Default)]
+pub struct RecordingEntropy {
+    pub requests: Rc<RefCell<Vec<usize>>>,
+}
+
+impl Entropy for RecordingEntropy {
+    type Error = std::convert::Infallible;
+
+    const APPROVED: Option<Approved> = Some(Approved::source::<Self>());
+
+    fn fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        self.requests.borrow_mut().push(bytes.len());
+        bytes.fill(0x5a);
+        Ok(())
+    }
+}
+
+impl CryptoEntropy for RecordingEntropy {}
+
+// Fails while `failing` is set.
+#[derive(Clone, Default)]
+pub struct FlakyEntropy {
+    pub failing: Rc<Cell<bool>>,
+}
+
+impl Entropy for FlakyEntropy {
+    type Error = &'static str;
+
+    const APPROVED: Option<Approved> = Some(Approved::source::<Self>());
+
+    fn fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        if self.failing.get() {
+            return Err("entropy source failed");
+        }
+        bytes.fill(0x5a);
+        Ok(())
+    }
+}
+
+impl CryptoEntropy for FlakyEntropy {}
@@ -0,0 +1,172 @@
+// SP 800-90A functional interface with state handles.
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use kondrbg::{
+        DrbgError, DrbgHmacSha256, Entropy,
+        functional::{DrbgTable, Mechanism, Status},
+    };
+
+    use crate::common::{FlakyEntropy, RecordingEntropy};
+
+    #[test]
+    fn test_functional() -> Result<(), DrbgError<<RecordingEntropy as Entropy>::Error>> {
+        let mut table = DrbgTable::with_entropy(RecordingEntropy::default());
+        let (status, state_handle) =
+            table.instantiate_function(Mechanism::HmacSha256, 128, false, b"personalization");
+        assert_eq!(status, Status::Success);
+        let state_handle = state_handle.unwrap();
+
+        // Same output as the typed API given the same entropy.
+        let mut drbg = DrbgHmacSha256::builder()
+            .entropy(RecordingEntropy::default())
+            .security_strength(128)
+            .personalization_string(b"personalization")
+            .build()?;
+        let mut bytes = [0; 32];
+        drbg.fill_bytes_with_ai(&mut bytes, b"additional")?;
+        let (status, pseudorandom_bits) =
+            table.generate_function(state_handle, 256, 128, false, b"additional");
+        assert_eq!(status, Status::Success);
+        assert_eq!(pseudorandom_bits.unwrap(), bytes);
+
+        // Invalid requests leave the instance usable.
+        for (
+            requested_number_of_bits,
+            requested_security_strength,
+            prediction_resistance_request,
+        ) in [
+            (255, 128, false),
+            (256, 192, false),
+            (256, 128, true),
+            ((1 << 19) + 8, 128, false),
+        ] {
+            let (status, pseudorandom_bits) = table.generate_function(
+                state_handle,
+                requested_number_of_bits,
+                requested_security_strength,
+                prediction_resistance_request,
+                &[],
+            );
+            assert_eq!(status, Status::ErrorFlag);
+            assert!(pseudorandom_bits.is_none());
+        }
+        assert_eq!(
+            table.reseed_function(state_handle, true, &[]),
+            Status::ErrorFlag
+        );
+        assert_eq!(
+            table.reseed_function(state_handle, false, &[]),
+            Status::Success
+        );
+        assert_eq!(
+            table
+                .generate_function(state_handle, 1 << 19, 128, false, &[])
+                .0,
+            Status::Success
+        );
+
+        // Handles are invalid once uninstantiated.
+        assert_eq!(table.uninstantiate_function(state_handle), Status::Success);
+        assert_eq!(
+            table.uninstantiate_function(state_handle),
+            Status::ErrorFlag
+        );
+        assert_eq!(
+            table.reseed_function(state_handle, false, &[]),
+            Status::ErrorFlag
+        );
+        assert_eq!(
+            table
+                .generate_function(state_handle, 256, 128, false, &[])
+                .0,
+            Status::ErrorFlag
+        );
+
+        // Prediction resistance can be requested when instantiated with the flag.
+        let (status, state_handle) =
+            table.instantiate_function(Mechanism::CtrAes256, 256, true, &[]);
+        assert_eq!(status, Status::Success);
+        let state_handle = state_handle.unwrap();
+        assert_ne!(
+            state_handle,
+            table
+                .instantiate_function(Mechanism::CtrAes256, 256, true, &[])
+                .1
+                .unwrap()
+        );
+        assert_eq!(
+            table.generate_function(state_handle, 256, 256, true, &[]).0,
+            Status::Success
+        );
+        assert_eq!(
+            table.reseed_function(state_handle, true, &[]),
+            Status::Success
+        );
+
+        assert_eq!(
+            table.instantiate_function(Mechanism::HashSha224, 256, false, &[]),
+            (Status::ErrorFlag, None)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_functional_entropy_failure() {
+        let entropy = FlakyEntropy::default();
+        let mut table = DrbgTable::with_entropy(entropy.clone());
+        let (status, state_handle) =
+            table.instantiate_function(Mechanism::HashSha256, 256, false, &[]);
+        assert_eq!(status, Status::Success);
+        let state_handle = state_handle.unwrap();
+
+        // Section 11.3, the instance stays in the error state until uninstantiated.
+        entropy.failing.set(true);
+        assert_eq!(
+            table.reseed_function(state_handle, false, &[]),
+            Status::CatastrophicErrorFlag
+        );
+        entropy.failing.set(false);
+        assert_eq!(
+            table.generate_function(state_handle, 256, 256, false, &[]),
+            (Status::CatastrophicErrorFlag, None)
+        );
+        assert_eq!(table.uninstantiate_function(state_handle), Status::Success);
+
+        entropy.failing.set(true);
+        assert_eq!(
+            table.instantiate_function(Mechanism::HashSha256, 256, false, &[]),
+            (Status::CatastrophicErrorFlag, None)
+        );
+    }
+
+    #[test]
+    fn test_functional_prediction_resistance_flag() {
+        let entropy = RecordingEntropy::default();
+        let mut table = DrbgTable::with_entropy(entropy.clone());
+        let (status, state_handle) =
+            table.instantiate_function(Mechanism::HmacSha256, 256, true, &[]);
+        assert_eq!(status, Status::Success);
+        let state_handle = state_handle.unwrap();
+        let reads = entropy.requests.borrow().len();
+
+        // The flag only permits prediction resistance, generating without a request does not reseed.
+        for _ in 0..3 {
+            assert_eq!(
+                table
+                    .generate_function(state_handle, 256, 256, false, &[])
+                    .0,
+                Status::Success
+            );
+        }
+        assert_eq!(entropy.requests.borrow().len(), reads);
+
+        assert_eq!(
+            table.generate_function(state_handle, 256, 256, true, &[]).0,
+            Status::Success
+        );
+        assert_eq!(entropy.requests.borrow().len(), reads + 1);
+    }
+}
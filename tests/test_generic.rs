@@ -0,0 +1,297 @@
+// Third-party mechanisms through the generic Drbg.
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use kondrbg::{
+        Cipher, Ctr, CtrNoDf, Drbg, DrbgError, DrbgVariant, Entropy, Hash, HashParams, Hmac, NoPr,
+        Pr, RandomBitGenerator, RequestMode,
+    };
+    use rand_core::TryRngCore;
+
+    use crate::common::RecordingEntropy;
+
+    type TestError = DrbgError<<RecordingEntropy as Entropy>::Error>;
+
+    // Not a secure mechanism, just counts up from a seeded state.
+    #[derive(Default)]
+    struct Counter {
+        state: u8,
+    }
+
+    impl DrbgVariant for Counter {
+        const NAME: &'static str = "Counter";
+        const MAX_RESEED_INTERVAL: u64 = 4;
+        const SECURITY_STRENGTH: usize = 16;
+        const MAX_BYTES_PER_REQUEST: usize = 8;
+        const OUTPUT_BLOCK_LEN: usize = 1;
+
+        type Params = ();
+
+        fn instantiate(
+            _params: &Self::Params,
+            entropy_input: &[u8],
+            nonce: &[u8],
+            personalization_string: &[u8],
+        ) -> Self {
+            let mut counter = Self::default();
+            counter.reseed(&[entropy_input, nonce].concat(), personalization_string);
+            counter
+        }
+
+        fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) {
+            self.state = [entropy_input, additional_input]
+                .concat()
+                .iter()
+                .fold(0, |state: u8, byte| state.wrapping_add(*byte));
+        }
+
+        fn generate(&mut self, bytes: &mut [u8], _additional_input: &[u8], _reseed_counter: u64) {
+            for byte in bytes {
+                self.state = self.state.wrapping_add(1);
+                *byte = self.state;
+            }
+        }
+
+        fn zeroize(&mut self) {
+            self.state = 0;
+        }
+
+        fn is_zeroized(&self) -> bool {
+            self.state == 0
+        }
+    }
+
+    #[test]
+    fn test_custom_variant() -> Result<(), TestError> {
+        let entropy = RecordingEntropy::default();
+        // Third-party mechanisms are never approved.
+        if cfg!(feature = "fips") {
+            assert!(matches!(
+                Drbg::<NoPr, Counter, _>::builder().entropy(entropy).build(),
+                Err(DrbgError::NotApproved)
+            ));
+            return Ok(());
+        }
+
+        let mut drbg = Drbg::<NoPr, Counter, _>::builder()
+            .entropy(entropy.clone())
+            .reseed_interval(2)
+            .build()?;
+        assert_eq!(drbg.security_strength(), 128);
+        assert_eq!(*entropy.requests.borrow(), [8, 16]);
+
+        // Requests are split on MAX_BYTES_PER_REQUEST, every chunk counts towards the reseed interval.
+        let mut bytes = [0; 20];
+        drbg.fill_bytes(&mut bytes)?;
+        assert_eq!(*entropy.requests.borrow(), [8, 16, 16]);
+        let seeded = 0x5au8.wrapping_mul(16);
+        assert_eq!(bytes[16], seeded.wrapping_add(1));
+
+        // The shared TryRngCore implementation.
+        drbg.try_next_u32()?;
+        assert_eq!(*entropy.requests.borrow(), [8, 16, 16]);
+        drbg.try_next_u32()?;
+        assert_eq!(*entropy.requests.borrow(), [8, 16, 16, 16]);
+
+        // Strict mode and the builder checks apply as well.
+        drbg = Drbg::<NoPr, Counter, _>::builder()
+            .entropy(entropy.clone())
+            .request_mode(RequestMode::Strict)
+            .build()?;
+        assert!(matches!(
+            drbg.fill_bytes(&mut bytes),
+            Err(DrbgError::RequestTooLarge)
+        ));
+        assert!(matches!(
+            Drbg::<NoPr, Counter, _>::builder()
+                .entropy(entropy.clone())
+                .reseed_interval(5)
+                .build(),
+            Err(DrbgError::ReseedIntervalTooLong)
+        ));
+
+        assert!(!drbg.is_approved());
+
+        // Without a known answer the self-test is refused, which does not put any DRBG into the error state.
+        assert!(matches!(drbg.self_test(), Err(DrbgError::NoKnownAnswer)));
+        assert!(!drbg.is_in_error_state());
+        assert!(matches!(
+            Drbg::<NoPr, Counter, _>::builder()
+                .entropy(entropy.clone())
+                .self_test(true)
+                .build(),
+            Err(DrbgError::NoKnownAnswer)
+        ));
+
+        // Prediction resistance reseeds before every request.
+        let entropy = RecordingEntropy::default();
+        let mut drbg = Drbg::<Pr, Counter, _>::builder()
+            .entropy(entropy.clone())
+            .build()?;
+        drbg.fill_bytes(&mut [0; 4])?;
+        drbg.fill_bytes(&mut [0; 4])?;
+        assert_eq!(*entropy.requests.borrow(), [8, 16, 16, 16]);
+
+        // The generic Drbg is a RandomBitGenerator like the generated types.
+        let rbg: &mut dyn RandomBitGenerator<Error = TestError> = &mut drbg;
+        assert_eq!(rbg.mechanism(), "Counter");
+        assert_eq!(rbg.security_strength(), 128);
+        assert!(rbg.prediction_resistance());
+        rbg.fill_bytes(&mut [0; 4])?;
+        assert_eq!(entropy.requests.borrow().len(), 5);
+        Ok(())
+    }
+
+    // Not a secure cipher, XORs the block with the key.
+    struct XorCipher([u8; 16]);
+
+    impl Cipher for XorCipher {
+        const NAME: &'static str = "XOR";
+        const BLOCK_LEN: usize = 16;
+        const KEY_LEN: usize = 16;
+
+        const SECURITY_STRENGTH: usize = 16;
+        const MAX_RESEED_INTERVAL: u64 = 1 << 48;
+        const MAX_BYTES_PER_REQUEST: usize = 1 << 16;
+
+        type Block = [u8; 16];
+        type Key = [u8; 16];
+        fn block_from_slice(slice: &[u8]) -> Self::Block {
+            slice.try_into().unwrap()
+        }
+        fn key_from_slice(slice: &[u8]) -> Self::Key {
+            slice.try_into().unwrap()
+        }
+
+        type Seed = [u8; 32];
+        fn seed_from_slice(slice: &[u8]) -> Self::Seed {
+            slice.try_into().unwrap()
+        }
+
+        fn new(key: &Self::Key) -> Self {
+            Self(*key)
+        }
+        fn block_encrypt(&self, block: &mut Self::Block) {
+            block
+                .iter_mut()
+                .zip(self.0)
+                .for_each(|(byte, key)| *byte ^= key);
+        }
+        fn block_encrypt_b2b(&self, block: &Self::Block) -> Self::Block {
+            let mut block = *block;
+            self.block_encrypt(&mut block);
+            block
+        }
+    }
+
+    #[test]
+    fn test_custom_cipher() {
+        let entropy = RecordingEntropy::default();
+        // Third-party ciphers are never approved.
+        if cfg!(feature = "fips") {
+            assert!(matches!(
+                Drbg::<NoPr, Ctr<XorCipher>, _>::builder()
+                    .entropy(entropy)
+                    .build(),
+                Err(DrbgError::NotApproved)
+            ));
+            return;
+        }
+
+        let mut drbg = Drbg::<NoPr, Ctr<XorCipher>, _>::builder()
+            .entropy(entropy.clone())
+            .ctr_len(32)
+            .build()
+            .unwrap();
+        assert_eq!(drbg.security_strength(), 128);
+        drbg.fill_bytes(&mut [0; 64]).unwrap();
+        assert!(!drbg.is_approved());
+        assert_eq!(drbg.mechanism(), "CTR_DRBG XOR use df");
+
+        let mut drbg = Drbg::<Pr, CtrNoDf<XorCipher>, _>::builder()
+            .entropy(entropy.clone())
+            .build()
+            .unwrap();
+        drbg.fill_bytes(&mut [0; 64]).unwrap();
+        assert_eq!(drbg.mechanism(), "CTR_DRBG XOR no df");
+        // Without df the entropy input is seedlen bytes.
+        assert_eq!(entropy.requests.borrow()[2..], [32, 32]);
+
+        assert!(matches!(
+            Drbg::<NoPr, Ctr<XorCipher>, _>::builder()
+                .entropy(entropy)
+                .ctr_len(3)
+                .build(),
+            Err(DrbgError::InvalidParameters)
+        ));
+    }
+
+    // Table 2 parameters for a 512 bit digest, known answers from a reference implementation.
+    struct Blake2b512Params;
+
+    impl HashParams for Blake2b512Params {
+        type Digest = blake2::Blake2b512;
+
+        const NAME: &'static str = "BLAKE2b-512";
+        const SEED_LEN: usize = 111;
+        const SECURITY_STRENGTH: usize = 32;
+
+        const HASH_DRBG_KAT: Option<[u8; 32]> = Some(hex_literal(
+            "efc781c9eedab4dfc462da51e29e76163226aa75e0e25fa53210b2d3bbcc40e5",
+        ));
+        const HMAC_DRBG_KAT: Option<[u8; 32]> = Some(hex_literal(
+            "bb141dde42702f3cf2d9286a516ed6d6e3dd9d5b35c0511b613081771603c9e7",
+        ));
+    }
+
+    const fn hex_literal(hex: &str) -> [u8; 32] {
+        const fn nibble(c: u8) -> u8 {
+            match c {
+                b'0'..=b'9' => c - b'0',
+                _ => c - b'a' + 10,
+            }
+        }
+        let hex = hex.as_bytes();
+        let mut bytes = [0; 32];
+        let mut i = 0;
+        while i < 32 {
+            bytes[i] = nibble(hex[2 * i]) << 4 | nibble(hex[2 * i + 1]);
+            i += 1;
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_custom_digest() {
+        // Third-party digests are never approved.
+        if cfg!(feature = "fips") {
+            assert!(matches!(
+                Drbg::<NoPr, Hash<Blake2b512Params>, _>::builder().build(),
+                Err(DrbgError::NotApproved)
+            ));
+            return;
+        }
+
+        // The self-tests check the generic Hash_DRBG and HMAC_DRBG against the known answers.
+        let mut drbg = Drbg::<NoPr, Hash<Blake2b512Params>, _>::builder()
+            .self_test(true)
+            .build()
+            .unwrap();
+        assert_eq!(drbg.security_strength(), 256);
+        assert!(drbg.self_test().is_ok());
+        drbg.fill_bytes(&mut [0; 256]).unwrap();
+        assert!(!drbg.is_approved());
+        assert_eq!(drbg.mechanism(), "Hash_DRBG BLAKE2b-512");
+
+        let mut drbg = Drbg::<Pr, Hmac<Blake2b512Params>, _>::builder()
+            .self_test(true)
+            .build()
+            .unwrap();
+        assert!(drbg.self_test().is_ok());
+        assert_eq!(drbg.mechanism(), "HMAC_DRBG BLAKE2b-512");
+        drbg.fill_bytes(&mut [0; 256]).unwrap();
+        assert!(!drbg.is_in_error_state());
+    }
+}
@@ -0,0 +1,687 @@
+// Behavior of the optional DRBG builder settings.
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+        time::Duration,
+    };
+
+    use kondrbg::{
+        AnyDrbg, Approved, AutoDrbg, CryptoEntropy, DrbgCtrAes128, DrbgCtrNoDfAes256, DrbgError,
+        DrbgHashSha224, DrbgHashSha384, DrbgHmacSha512, DrbgPrCtrAes128, DrbgPrHmacSha512_256,
+        Entropy, ErrorPolicy, NoSource, RandomBitGenerator, RequestMode,
+    };
+
+    use crate::common::{FlakyEntropy, RecordingEntropy};
+
+    type TestError = DrbgError<<RecordingEntropy as Entropy>::Error>;
+
+    // Half a bit of min-entropy per bit, supporting up to 128 bits of security strength.
+    #[derive(Clone, Default)]
+    struct WeakEntropy {
+        requests: Rc<RefCell<Vec<usize>>>,
+    }
+
+    impl Entropy for WeakEntropy {
+        type Error = std::convert::Infallible;
+
+        const APPROVED: Option<Approved> = Some(Approved::source::<Self>());
+        const MIN_ENTROPY_PER_BIT: f64 = 0.5;
+        const MAX_SECURITY_STRENGTH: usize = 128;
+
+        fn fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), Self::Error> {
+            self.requests.borrow_mut().push(bytes.len());
+            bytes.fill(0x5a);
+            Ok(())
+        }
+    }
+
+    impl CryptoEntropy for WeakEntropy {}
+
+    // Returns nothing while `stalls` is positive, then at most 5 bytes per read.
+    #[derive(Clone, Default)]
+    struct PartialEntropy {
+        stalls: Rc<Cell<usize>>,
+    }
+
+    impl Entropy for PartialEntropy {
+        type Error = std::convert::Infallible;
+
+        const APPROVED: Option<Approved> = Some(Approved::source::<Self>());
+
+        fn fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), Self::Error> {
+            bytes.fill(0x5a);
+            Ok(())
+        }
+
+        fn fill_partial(&mut self, bytes: &mut [u8]) -> Result<usize, Self::Error> {
+            if self.stalls.get() > 0 {
+                self.stalls.set(self.stalls.get() - 1);
+                return Ok(0);
+            }
+            let len = bytes.len().min(5);
+            bytes[..len].fill(0x5a);
+            Ok(len)
+        }
+    }
+
+    impl CryptoEntropy for PartialEntropy {}
+
+    // Not marked approved, like any custom source.
+    struct UnapprovedEntropy;
+
+    impl Entropy for UnapprovedEntropy {
+        type Error = std::convert::Infallible;
+
+        fn fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), Self::Error> {
+            bytes.fill(0x5a);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_security_strength() -> Result<(), TestError> {
+        // Nonce first, then the entropy input, both sized by the requested security strength.
+        let entropy = RecordingEntropy::default();
+        let drbg = DrbgHmacSha512::builder()
+            .entropy(entropy.clone())
+            .security_strength(128)
+            .build()?;
+        assert_eq!(drbg.security_strength(), 128);
+        assert_eq!(*entropy.requests.borrow(), [8, 16]);
+
+        // Requested strengths are rounded up to the next supported strength.
+        let drbg = DrbgHmacSha512::builder()
+            .entropy(RecordingEntropy::default())
+            .security_strength(100)
+            .build()?;
+        assert_eq!(drbg.security_strength(), 112);
+
+        let drbg = DrbgHmacSha512::builder()
+            .entropy(RecordingEntropy::default())
+            .build()?;
+        assert_eq!(drbg.security_strength(), 256);
+
+        // Without df the entropy input is always seedlen.
+        let entropy = RecordingEntropy::default();
+        let drbg = DrbgCtrNoDfAes256::builder()
+            .entropy(entropy.clone())
+            .security_strength(128)
+            .build()?;
+        assert_eq!(drbg.security_strength(), 128);
+        assert_eq!(*entropy.requests.borrow(), [48]);
+
+        // The nonce must cover half of the instantiated security strength.
+        let result = DrbgHmacSha512::builder()
+            .entropy(RecordingEntropy::default())
+            .security_strength(128)
+            .nonce(&[0; 7])
+            .build();
+        assert!(matches!(result, Err(DrbgError::NonceTooShort)));
+
+        let result = DrbgHashSha224::builder()
+            .entropy(RecordingEntropy::default())
+            .security_strength(256)
+            .build();
+        assert!(matches!(
+            result,
+            Err(DrbgError::SecurityStrengthNotSupported)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_entropy_input_len() -> Result<(), TestError> {
+        // The same length is requested on instantiate and on every reseed.
+        let entropy = RecordingEntropy::default();
+        let mut drbg = DrbgHmacSha512::builder()
+            .entropy(entropy.clone())
+            .entropy_input_len(64)
+            .reseed_interval(1)
+            .build()?;
+        let mut bytes = [0; 16];
+        drbg.fill_bytes(&mut bytes)?;
+        drbg.fill_bytes(&mut bytes)?;
+        assert_eq!(*entropy.requests.borrow(), [16, 64, 64]);
+
+        // The minimum follows the requested security strength.
+        DrbgHmacSha512::builder()
+            .entropy(RecordingEntropy::default())
+            .security_strength(128)
+            .entropy_input_len(16)
+            .build()?;
+        let result = DrbgHmacSha512::builder()
+            .entropy(RecordingEntropy::default())
+            .entropy_input_len(31)
+            .build();
+        assert!(matches!(result, Err(DrbgError::EntropyInputTooShort)));
+
+        // Without df the entropy input must be exactly seedlen.
+        let result = DrbgCtrNoDfAes256::builder()
+            .entropy(RecordingEntropy::default())
+            .entropy_input_len(49)
+            .build();
+        assert!(matches!(result, Err(DrbgError::EntropyInputTooLong)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_entropy_source() -> Result<(), TestError> {
+        // Nonce and entropy input are over-sampled to reach the required min-entropy.
+        let entropy = WeakEntropy::default();
+        let mut drbg = DrbgHmacSha512::builder()
+            .entropy(entropy.clone())
+            .security_strength(128)
+            .build()?;
+        drbg.reseed()?;
+        assert_eq!(*entropy.requests.borrow(), [16, 32, 32]);
+        assert!(drbg.is_approved());
+
+        // The source cannot support more than 128 bits of security strength.
+        assert!(matches!(
+            DrbgHmacSha512::builder()
+                .entropy(WeakEntropy::default())
+                .build(),
+            Err(DrbgError::EntropySourceTooWeak)
+        ));
+        assert!(
+            !DrbgHmacSha512::builder()
+                .entropy(WeakEntropy::default())
+                .is_approved()
+        );
+
+        // Explicit entropy input lengths still need enough min-entropy.
+        assert!(matches!(
+            DrbgHmacSha512::builder()
+                .entropy(WeakEntropy::default())
+                .security_strength(128)
+                .entropy_input_len(31)
+                .build(),
+            Err(DrbgError::EntropyInputTooShort)
+        ));
+
+        // CTR DRBGs without df need a full entropy source.
+        assert!(matches!(
+            DrbgCtrNoDfAes256::builder()
+                .entropy(WeakEntropy::default())
+                .security_strength(128)
+                .build(),
+            Err(DrbgError::EntropySourceTooWeak)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_partial_entropy() -> Result<(), TestError> {
+        // Partial reads are accumulated into the same entropy input.
+        let entropy = PartialEntropy::default();
+        let mut partial = DrbgHmacSha512::builder()
+            .entropy(entropy.clone())
+            .build()
+            .unwrap();
+        let mut full = DrbgHmacSha512::builder()
+            .entropy(RecordingEntropy::default())
+            .build()?;
+        let mut partial_bytes = [0; 64];
+        let mut full_bytes = [0; 64];
+        partial.fill_bytes(&mut partial_bytes).unwrap();
+        full.fill_bytes(&mut full_bytes)?;
+        assert_eq!(partial_bytes, full_bytes);
+
+        // Without a timeout an empty read fails, the DRBG can be reseeded again once the source recovers.
+        entropy.stalls.set(1);
+        assert!(matches!(partial.reseed(), Err(DrbgError::EntropyTimeout)));
+        assert!(partial.reseed().is_ok());
+        entropy.stalls.set(1);
+        assert!(matches!(
+            DrbgHmacSha512::builder().entropy(entropy.clone()).build(),
+            Err(DrbgError::EntropyTimeout)
+        ));
+
+        // A timeout is an entropy source failure, a latching DRBG stays in the error state.
+        let mut latching = DrbgHmacSha512::builder()
+            .entropy(entropy.clone())
+            .error_policy(ErrorPolicy::Latching)
+            .build()
+            .unwrap();
+        entropy.stalls.set(1);
+        assert!(matches!(latching.reseed(), Err(DrbgError::EntropyTimeout)));
+        assert!(latching.is_in_error_state());
+        assert!(matches!(latching.reseed(), Err(DrbgError::ErrorState)));
+
+        // A bounded wait rides out a briefly blocking source.
+        entropy.stalls.set(3);
+        let mut partial = DrbgHmacSha512::builder()
+            .entropy(entropy.clone())
+            .entropy_timeout(Duration::from_secs(1))
+            .build()
+            .unwrap();
+        entropy.stalls.set(3);
+        assert!(partial.reseed().is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_entropy_input() -> Result<(), TestError> {
+        if cfg!(feature = "fips") {
+            assert!(matches!(
+                DrbgHmacSha512::builder()
+                    .entropy(NoSource)
+                    .nonce(&[0x5a; 16])
+                    .build_with_entropy_input(&[0x5a; 32]),
+                Err(DrbgError::NotApproved)
+            ));
+            return Ok(());
+        }
+
+        // Same output as reading the same bytes from the entropy source.
+        let mut supplied = DrbgHmacSha512::builder()
+            .entropy(NoSource)
+            .nonce(&[0x5a; 16])
+            .reseed_interval(1)
+            .build_with_entropy_input(&[0x5a; 32])
+            .unwrap();
+        let mut read = DrbgHmacSha512::builder()
+            .entropy(RecordingEntropy::default())
+            .nonce(&[0x5a; 16])
+            .reseed_interval(1)
+            .build()?;
+        let mut supplied_bytes = [0; 64];
+        let mut read_bytes = [0; 64];
+        supplied.fill_bytes(&mut supplied_bytes).unwrap();
+        read.fill_bytes(&mut read_bytes)?;
+        assert_eq!(supplied_bytes, read_bytes);
+
+        // Without a source, reseeds have to be supplied by the caller.
+        assert!(matches!(
+            supplied.fill_bytes(&mut supplied_bytes),
+            Err(DrbgError::ReseedRequired)
+        ));
+        assert!(matches!(supplied.reseed(), Err(DrbgError::ReseedRequired)));
+        assert!(matches!(
+            supplied.reseed_with_entropy_input(&[0x5a; 31], &[]),
+            Err(DrbgError::EntropyInputTooShort)
+        ));
+        supplied
+            .reseed_with_entropy_input(&[0x5a; 32], b"additional")
+            .unwrap();
+        read.reseed_with_ai(b"additional")?;
+        supplied.fill_bytes(&mut supplied_bytes).unwrap();
+        read.fill_bytes(&mut read_bytes)?;
+        assert_eq!(supplied_bytes, read_bytes);
+
+        // The entropy input length is checked against the mechanism.
+        let builder = || DrbgCtrNoDfAes256::builder().entropy(NoSource);
+        assert!(matches!(
+            builder().build_with_entropy_input(&[0x5a; 47]),
+            Err(DrbgError::EntropyInputTooShort)
+        ));
+        assert!(matches!(
+            builder().build_with_entropy_input(&[0x5a; 49]),
+            Err(DrbgError::EntropyInputTooLong)
+        ));
+        assert!(builder().build_with_entropy_input(&[0x5a; 48]).is_ok());
+
+        // A generated nonce still needs the source.
+        assert!(matches!(
+            DrbgHmacSha512::builder()
+                .entropy(NoSource)
+                .build_with_entropy_input(&[0x5a; 32]),
+            Err(DrbgError::EntropyError(_))
+        ));
+
+        // Reaching the reseed interval is not an entropy source failure, even for a latching DRBG.
+        let mut latching = DrbgHmacSha512::builder()
+            .entropy(NoSource)
+            .nonce(&[0x5a; 16])
+            .reseed_interval(1)
+            .error_policy(ErrorPolicy::Latching)
+            .build_with_entropy_input(&[0x5a; 32])
+            .unwrap();
+        latching.fill_bytes(&mut supplied_bytes).unwrap();
+        assert!(matches!(
+            latching.fill_bytes(&mut supplied_bytes),
+            Err(DrbgError::ReseedRequired)
+        ));
+        assert!(!latching.is_in_error_state());
+        latching
+            .reseed_with_entropy_input(&[0x5a; 32], &[])
+            .unwrap();
+        latching.fill_bytes(&mut supplied_bytes).unwrap();
+
+        // Prediction resistance cannot be provided without a source.
+        assert!(matches!(
+            DrbgPrHmacSha512_256::builder()
+                .entropy(NoSource)
+                .nonce(&[0x5a; 16])
+                .build_with_entropy_input(&[0x5a; 32]),
+            Err(DrbgError::PredictionResistanceNotSupported)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_request_mode() -> Result<(), TestError> {
+        // A 4 bit counter limits requests to (2^4 - 4) * 16 = 192 bytes.
+        let mut drbg = DrbgCtrAes128::builder()
+            .entropy(RecordingEntropy::default())
+            .ctr_len(4)
+            .request_mode(RequestMode::Strict)
+            .build()?;
+        drbg.fill_bytes(&mut [0; 192])?;
+        assert!(matches!(
+            drbg.fill_bytes(&mut [0; 193]),
+            Err(DrbgError::RequestTooLarge)
+        ));
+
+        // Chunked requests use the additional input once, exactly like consecutive requests.
+        let mut chunked = DrbgCtrAes128::builder()
+            .entropy(RecordingEntropy::default())
+            .ctr_len(4)
+            .build()?;
+        let mut consecutive = DrbgCtrAes128::builder()
+            .entropy(RecordingEntropy::default())
+            .ctr_len(4)
+            .build()?;
+        let mut chunked_bytes = [0; 400];
+        let mut consecutive_bytes = [0; 400];
+        chunked.fill_bytes_with_ai(&mut chunked_bytes, b"additional")?;
+        let (first, rest) = consecutive_bytes.split_at_mut(192);
+        consecutive.fill_bytes_with_ai(first, b"additional")?;
+        let (second, third) = rest.split_at_mut(192);
+        consecutive.fill_bytes(second)?;
+        consecutive.fill_bytes(third)?;
+        assert_eq!(chunked_bytes, consecutive_bytes);
+
+        // With prediction resistance only the first reseed gets the additional input.
+        let entropy = RecordingEntropy::default();
+        let mut chunked = DrbgPrCtrAes128::builder()
+            .entropy(entropy.clone())
+            .ctr_len(4)
+            .build()?;
+        let mut consecutive = DrbgPrCtrAes128::builder()
+            .entropy(RecordingEntropy::default())
+            .ctr_len(4)
+            .build()?;
+        chunked.fill_bytes_with_ai(&mut chunked_bytes, b"additional")?;
+        let (first, rest) = consecutive_bytes.split_at_mut(192);
+        consecutive.fill_bytes_with_ai(first, b"additional")?;
+        let (second, third) = rest.split_at_mut(192);
+        consecutive.fill_bytes(second)?;
+        consecutive.fill_bytes(third)?;
+        assert_eq!(chunked_bytes, consecutive_bytes);
+        // Nonce, entropy input, then one reseed per chunk.
+        assert_eq!(*entropy.requests.borrow(), [8, 16, 16, 16, 16]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_policy() -> Result<(), DrbgError<<FlakyEntropy as Entropy>::Error>> {
+        // A transient failure only affects the failing request.
+        let entropy = FlakyEntropy::default();
+        let mut drbg = DrbgHmacSha512::builder().entropy(entropy.clone()).build()?;
+        entropy.failing.set(true);
+        assert!(matches!(drbg.reseed(), Err(DrbgError::EntropyError(_))));
+        assert!(!drbg.is_in_error_state());
+        drbg.fill_bytes(&mut [0; 16])?;
+        entropy.failing.set(false);
+        drbg.reseed()?;
+
+        // A latching failure refuses every later request, even once the source recovers.
+        let entropy = FlakyEntropy::default();
+        let mut drbg = DrbgPrCtrAes128::builder()
+            .entropy(entropy.clone())
+            .error_policy(ErrorPolicy::Latching)
+            .build()?;
+        drbg.fill_bytes(&mut [0; 16])?;
+        entropy.failing.set(true);
+        assert!(matches!(
+            drbg.fill_bytes(&mut [0; 16]),
+            Err(DrbgError::EntropyError(_))
+        ));
+        assert!(drbg.is_in_error_state());
+        entropy.failing.set(false);
+        assert!(matches!(
+            drbg.fill_bytes(&mut [0; 16]),
+            Err(DrbgError::ErrorState)
+        ));
+        assert!(matches!(drbg.reseed(), Err(DrbgError::ErrorState)));
+
+        // Uninstantiating hands back the source for a fresh instance.
+        let mut drbg = DrbgPrCtrAes128::builder()
+            .entropy(drbg.uninstantiate())
+            .error_policy(ErrorPolicy::Latching)
+            .build()?;
+        drbg.fill_bytes(&mut [0; 16])?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_continuous_test() -> Result<(), TestError> {
+        // Apart from the discarded first block, the continuous test does not change the output.
+        let mut tested = DrbgHashSha384::builder()
+            .entropy(RecordingEntropy::default())
+            .continuous_test(true)
+            .build()?;
+        let mut untested = DrbgHashSha384::builder()
+            .entropy(RecordingEntropy::default())
+            .build()?;
+        untested.fill_bytes(&mut [0; 48])?;
+        for len in [1, 47, 48, 100, 4096] {
+            let mut tested_bytes = vec![0; len];
+            let mut untested_bytes = vec![0; len];
+            tested.fill_bytes_with_ai(&mut tested_bytes, b"additional")?;
+            untested.fill_bytes_with_ai(&mut untested_bytes, b"additional")?;
+            assert_eq!(tested_bytes, untested_bytes);
+        }
+
+        // Large requests are split on whole 48 byte blocks.
+        tested.fill_bytes(&mut vec![0; (1 << 16) + 5])?;
+        assert!(!tested.is_in_error_state());
+        Ok(())
+    }
+
+    #[test]
+    fn test_continuous_test_strict() -> Result<(), TestError> {
+        // Strict requests keep the full max_number_of_bits_per_request, 65536 % 28 = 16.
+        let mut tested = DrbgHashSha224::builder()
+            .entropy(RecordingEntropy::default())
+            .continuous_test(true)
+            .request_mode(RequestMode::Strict)
+            .build()?;
+        let mut untested = DrbgHashSha224::builder()
+            .entropy(RecordingEntropy::default())
+            .request_mode(RequestMode::Strict)
+            .build()?;
+        untested.fill_bytes(&mut [0; 28])?;
+        let mut tested_bytes = vec![0; 1 << 16];
+        let mut untested_bytes = vec![0; 1 << 16];
+        tested.fill_bytes(&mut tested_bytes)?;
+        untested.fill_bytes(&mut untested_bytes)?;
+        assert_eq!(tested_bytes, untested_bytes);
+        assert!(matches!(
+            tested.fill_bytes(&mut vec![0; (1 << 16) + 1]),
+            Err(DrbgError::RequestTooLarge)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_approved() -> Result<(), TestError> {
+        assert!(DrbgHmacSha512::builder().is_approved());
+        assert!(DrbgHmacSha512::new().unwrap().is_approved());
+
+        let builder = DrbgHashSha224::builder().entropy(RecordingEntropy::default());
+        assert!(builder.is_approved());
+        let drbg = builder.reseed_interval(1000).build()?;
+        assert!(drbg.is_approved());
+
+        // Parameters outside of the approved ranges.
+        let builder = || DrbgHashSha224::builder().entropy(RecordingEntropy::default());
+        assert!(!builder().reseed_interval(0).is_approved());
+        assert!(!builder().reseed_interval((1 << 48) + 1).is_approved());
+        assert!(!builder().security_strength(256).is_approved());
+        assert!(!builder().nonce(&[0; 11]).is_approved());
+        assert!(builder().nonce(&[0; 12]).is_approved());
+
+        // Mechanisms without a nonce are only approved without one.
+        let builder = || DrbgCtrNoDfAes256::builder().entropy(RecordingEntropy::default());
+        assert!(builder().is_approved());
+        assert!(!builder().nonce(&[0; 16]).is_approved());
+
+        // Custom entropy sources have to opt in.
+        let builder = DrbgCtrAes128::builder().entropy(UnapprovedEntropy);
+        assert!(!builder.is_approved());
+        #[cfg(not(feature = "fips"))]
+        assert!(!builder.build().unwrap().is_approved());
+        #[cfg(feature = "fips")]
+        assert!(matches!(builder.build(), Err(DrbgError::NotApproved)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_random_bit_generator() -> Result<(), TestError> {
+        let entropy = RecordingEntropy::default();
+        let mut drbgs: Vec<Box<dyn RandomBitGenerator<Error = TestError>>> = vec![
+            Box::new(DrbgCtrAes128::builder().entropy(entropy.clone()).build()?),
+            Box::new(
+                DrbgCtrNoDfAes256::builder()
+                    .entropy(entropy.clone())
+                    .build()?,
+            ),
+            Box::new(DrbgHashSha224::builder().entropy(entropy.clone()).build()?),
+            Box::new(
+                DrbgPrHmacSha512_256::builder()
+                    .entropy(entropy.clone())
+                    .security_strength(128)
+                    .build()?,
+            ),
+        ];
+
+        let described: Vec<_> = drbgs
+            .iter()
+            .map(|drbg| {
+                (
+                    drbg.mechanism(),
+                    drbg.prediction_resistance(),
+                    drbg.security_strength(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            described,
+            [
+                ("CTR_DRBG AES-128 use df", false, 128),
+                ("CTR_DRBG AES-256 no df", false, 256),
+                ("Hash_DRBG SHA-224", false, 192),
+                ("HMAC_DRBG SHA-512/256", true, 128),
+            ]
+        );
+
+        for drbg in &mut drbgs {
+            drbg.fill_bytes(&mut [0; 64])?;
+            drbg.fill_bytes_with_ai(&mut [0; 64], b"additional input")?;
+            drbg.reseed()?;
+            drbg.reseed_with_ai(b"additional input")?;
+        }
+
+        // Only the prediction resistant DRBG reseeds on every request.
+        entropy.requests.borrow_mut().clear();
+        for drbg in &mut drbgs {
+            drbg.fill_bytes(&mut [0; 16])?;
+        }
+        assert_eq!(*entropy.requests.borrow(), [16]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_any_drbg() -> Result<(), TestError> {
+        let any = |mechanism, prediction_resistance| {
+            AnyDrbg::with_entropy(
+                mechanism,
+                prediction_resistance,
+                RecordingEntropy::default(),
+            )
+        };
+        for mechanism in AnyDrbg::MECHANISMS {
+            match any(mechanism, false) {
+                Ok(drbg) => assert_eq!(drbg.mechanism(), *mechanism),
+                // The fips feature refuses the legacy and other non-approved mechanisms.
+                Err(error) => assert!(matches!(error, DrbgError::NotApproved)),
+            }
+        }
+        assert!(AnyDrbg::MECHANISMS.contains(&"CTR_DRBG AES-256 no df"));
+        assert!(AnyDrbg::MECHANISMS.contains(&"HMAC_DRBG SHA3-512"));
+
+        for (identifier, mechanism) in [
+            ("CTR_DRBG AES-256 use df", "CTR_DRBG AES-256 use df"),
+            ("[AES-256 use df]", "CTR_DRBG AES-256 use df"),
+            ("[AES-128 no df]", "CTR_DRBG AES-128 no df"),
+            ("ctr-nodf-aes192", "CTR_DRBG AES-192 no df"),
+            ("HMAC_DRBG [SHA-256]", "HMAC_DRBG SHA-256"),
+            ("hmac-sha256", "HMAC_DRBG SHA-256"),
+            ("drbg_hash_sha512/224", "Hash_DRBG SHA-512/224"),
+            ("Hash_DRBG SHA3-384", "Hash_DRBG SHA3-384"),
+        ] {
+            assert_eq!(any(identifier, false)?.mechanism(), mechanism);
+        }
+        for identifier in ["[SHA-256]", "hmac-md5", ""] {
+            assert!(matches!(
+                any(identifier, false),
+                Err(DrbgError::UnknownMechanism)
+            ));
+        }
+
+        // Operations are forwarded to the selected DRBG.
+        let mut drbg = any("hash-sha384", true)?;
+        assert!(drbg.prediction_resistance());
+        assert_eq!(drbg.security_strength(), 256);
+        assert!(drbg.is_approved());
+        drbg.fill_bytes(&mut [0; 64])?;
+        drbg.fill_bytes_pr(&mut [0; 64], b"additional input")?;
+        drbg.reseed_with_entropy_input(&[0; 32], &[])?;
+        assert!(drbg.self_test().is_ok());
+        let entropy = drbg.uninstantiate();
+        assert_eq!(*entropy.requests.borrow(), [16, 32, 32, 32]);
+
+        let mut drbg = any("ctr-aes128", false)?;
+        assert!(!drbg.prediction_resistance());
+        drbg.fill_bytes_pr(&mut [0; 16], &[])?;
+        assert_eq!(drbg.uninstantiate().requests.borrow().len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_auto_drbg() -> Result<(), TestError> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        assert_eq!(
+            AutoDrbg::aes_available(),
+            std::arch::is_x86_feature_detected!("aes")
+        );
+        let expected = if AutoDrbg::aes_available() {
+            "CTR_DRBG AES-256 use df"
+        } else {
+            "HMAC_DRBG SHA-512"
+        };
+        assert_eq!(AutoDrbg::select(), expected);
+
+        let entropy = RecordingEntropy::default();
+        let mut drbg = AutoDrbg::with_entropy(false, entropy.clone())?;
+        assert_eq!(drbg.mechanism(), expected);
+        assert!(!drbg.prediction_resistance());
+        // The same security strength whichever mechanism was selected.
+        assert_eq!(drbg.security_strength(), 256);
+        assert!(drbg.is_approved());
+        drbg.fill_bytes(&mut [0; 64])?;
+        drbg.reseed()?;
+
+        let mut drbg = AutoDrbg::with_entropy(true, entropy)?;
+        assert!(drbg.prediction_resistance());
+        drbg.fill_bytes(&mut [0; 64])?;
+        Ok(())
+    }
+}
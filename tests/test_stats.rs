@@ -7,112 +7,61 @@
 
 #[cfg(test)]
 mod tests {
-    use kondrbg::{
-        DrbgCtrAes128, DrbgCtrAes192, DrbgCtrAes256, DrbgError, DrbgHashSha224, DrbgHashSha256,
-        DrbgHashSha384, DrbgHashSha512, DrbgHashSha512_224, DrbgHashSha512_256, DrbgHmacSha224,
-        DrbgHmacSha256, DrbgHmacSha384, DrbgHmacSha512, DrbgHmacSha512_224, DrbgHmacSha512_256,
-        DrbgPrCtrAes128, DrbgPrCtrAes192, DrbgPrCtrAes256, DrbgPrHashSha224, DrbgPrHashSha256,
-        DrbgPrHashSha384, DrbgPrHashSha512, DrbgPrHashSha512_224, DrbgPrHashSha512_256,
-        DrbgPrHmacSha224, DrbgPrHmacSha256, DrbgPrHmacSha384, DrbgPrHmacSha512,
-        DrbgPrHmacSha512_224, DrbgPrHmacSha512_256,
-    };
+    use kondrbg::{AnyDrbg, DrbgError};
     use rand_core::{OsRng, TryRngCore};
     use special_fun::cephes_double::{erfc, igamc};
 
     type TestError = DrbgError<<OsRng as TryRngCore>::Error>;
 
-    fn gen_with(bytes: &mut [u8], rng: &str) -> Result<(), TestError> {
-        match rng {
-            "drbg_hash_sha224" => DrbgHashSha224::new()?.fill_bytes(bytes),
-            "drbg_pr_hash_sha224" => DrbgPrHashSha224::new()?.fill_bytes(bytes),
-            "drbg_hash_sha256" => DrbgHashSha256::new()?.fill_bytes(bytes),
-            "drbg_pr_hash_sha256" => DrbgPrHashSha256::new()?.fill_bytes(bytes),
-            "drbg_hash_sha384" => DrbgHashSha384::new()?.fill_bytes(bytes),
-            "drbg_pr_hash_sha384" => DrbgPrHashSha384::new()?.fill_bytes(bytes),
-            "drbg_hash_sha512" => DrbgHashSha512::new()?.fill_bytes(bytes),
-            "drbg_pr_hash_sha512" => DrbgPrHashSha512::new()?.fill_bytes(bytes),
-            "drbg_hash_sha512/224" => DrbgHashSha512_224::new()?.fill_bytes(bytes),
-            "drbg_pr_hash_sha512/224" => DrbgPrHashSha512_224::new()?.fill_bytes(bytes),
-            "drbg_hash_sha512/256" => DrbgHashSha512_256::new()?.fill_bytes(bytes),
-            "drbg_pr_hash_sha512/256" => DrbgPrHashSha512_256::new()?.fill_bytes(bytes),
-            "drbg_hmac_sha224" => DrbgHmacSha224::new()?.fill_bytes(bytes),
-            "drbg_pr_hmac_sha224" => DrbgPrHmacSha224::new()?.fill_bytes(bytes),
-            "drbg_hmac_sha256" => DrbgHmacSha256::new()?.fill_bytes(bytes),
-            "drbg_pr_hmac_sha256" => DrbgPrHmacSha256::new()?.fill_bytes(bytes),
-            "drbg_hmac_sha384" => DrbgHmacSha384::new()?.fill_bytes(bytes),
-            "drbg_pr_hmac_sha384" => DrbgPrHmacSha384::new()?.fill_bytes(bytes),
-            "drbg_hmac_sha512" => DrbgHmacSha512::new()?.fill_bytes(bytes),
-            "drbg_pr_hmac_sha512" => DrbgPrHmacSha512::new()?.fill_bytes(bytes),
-            "drbg_hmac_sha512/224" => DrbgHmacSha512_224::new()?.fill_bytes(bytes),
-            "drbg_pr_hmac_sha512/224" => DrbgPrHmacSha512_224::new()?.fill_bytes(bytes),
-            "drbg_hmac_sha512/256" => DrbgHmacSha512_256::new()?.fill_bytes(bytes),
-            "drbg_pr_hmac_sha512/256" => DrbgPrHmacSha512_256::new()?.fill_bytes(bytes),
-            "drbg_ctr_aes128" => DrbgCtrAes128::new()?.fill_bytes(bytes),
-            "drbg_pr_ctr_aes128" => DrbgPrCtrAes128::new()?.fill_bytes(bytes),
-            "drbg_ctr_aes192" => DrbgCtrAes192::new()?.fill_bytes(bytes),
-            "drbg_pr_ctr_aes192" => DrbgPrCtrAes192::new()?.fill_bytes(bytes),
-            "drbg_ctr_aes256" => DrbgCtrAes256::new()?.fill_bytes(bytes),
-            "drbg_pr_ctr_aes256" => DrbgPrCtrAes256::new()?.fill_bytes(bytes),
-            name => unreachable!(
-                "Invalid Drbg name: {name}. Valid names are of the form drbg_?(pr_)(hash|hmac|ctr)_(sha224|sha256|sha384|sha512|sha512/224|sha512/256|aes128|aes192|aes256)"
-            ),
-        }
-    }
-
     fn test_all(bytes: &mut [u8], f: fn(&[u8]) -> f64) -> Result<(), TestError> {
-        test_one(bytes, f, "drbg_hash_sha224")?;
-        test_one(bytes, f, "drbg_pr_hash_sha224")?;
-        test_one(bytes, f, "drbg_hash_sha256")?;
-        test_one(bytes, f, "drbg_pr_hash_sha256")?;
-        test_one(bytes, f, "drbg_hash_sha384")?;
-        test_one(bytes, f, "drbg_pr_hash_sha384")?;
-        test_one(bytes, f, "drbg_hash_sha512")?;
-        test_one(bytes, f, "drbg_pr_hash_sha512")?;
-        test_one(bytes, f, "drbg_hash_sha512/224")?;
-        test_one(bytes, f, "drbg_pr_hash_sha512/224")?;
-        test_one(bytes, f, "drbg_hash_sha512/256")?;
-        test_one(bytes, f, "drbg_pr_hash_sha512/256")?;
-        test_one(bytes, f, "drbg_hmac_sha224")?;
-        test_one(bytes, f, "drbg_pr_hmac_sha224")?;
-        test_one(bytes, f, "drbg_hmac_sha256")?;
-        test_one(bytes, f, "drbg_pr_hmac_sha256")?;
-        test_one(bytes, f, "drbg_hmac_sha384")?;
-        test_one(bytes, f, "drbg_pr_hmac_sha384")?;
-        test_one(bytes, f, "drbg_hmac_sha512")?;
-        test_one(bytes, f, "drbg_pr_hmac_sha512")?;
-        test_one(bytes, f, "drbg_hmac_sha512/224")?;
-        test_one(bytes, f, "drbg_pr_hmac_sha512/224")?;
-        test_one(bytes, f, "drbg_hmac_sha512/256")?;
-        test_one(bytes, f, "drbg_pr_hmac_sha512/256")?;
-        test_one(bytes, f, "drbg_ctr_aes128")?;
-        test_one(bytes, f, "drbg_pr_ctr_aes128")?;
-        test_one(bytes, f, "drbg_ctr_aes192")?;
-        test_one(bytes, f, "drbg_pr_ctr_aes192")?;
-        test_one(bytes, f, "drbg_ctr_aes256")?;
-        test_one(bytes, f, "drbg_pr_ctr_aes256")?;
+        // The fips feature refuses the legacy and other non-approved mechanisms.
+        let mechanisms: Vec<_> = AnyDrbg::MECHANISMS
+            .iter()
+            .filter(|mechanism| {
+                !matches!(AnyDrbg::new(mechanism, false), Err(DrbgError::NotApproved))
+            })
+            .collect();
+        // Every mechanism with and without prediction resistance is one comparison.
+        let comparisons = 2 * mechanisms.len();
+        for mechanism in mechanisms {
+            test_one(bytes, f, mechanism, false, comparisons)?;
+            test_one(bytes, f, mechanism, true, comparisons)?;
+        }
         Ok(())
     }
 
     const RUNS: usize = 1_000;
 
-    fn test_one(bytes: &mut [u8], f: fn(&[u8]) -> f64, rng: &str) -> Result<(), TestError> {
+    fn test_one(
+        bytes: &mut [u8],
+        f: fn(&[u8]) -> f64,
+        mechanism: &str,
+        prediction_resistance: bool,
+        comparisons: usize,
+    ) -> Result<(), TestError> {
         let mut p_vals = Vec::with_capacity(RUNS);
         for _ in 0..RUNS {
-            gen_with(bytes, rng)?;
+            AnyDrbg::new(mechanism, prediction_resistance)?.fill_bytes(bytes)?;
             p_vals.push(f(bytes));
         }
+        let rng = if prediction_resistance {
+            format!("{mechanism} (prediction resistance)")
+        } else {
+            mechanism.to_string()
+        };
         println!();
         println!("vvvvvvvvvv {rng} vvvvvvvvvv");
         println!("BYTES: {}", bytes.len());
         println!("RUNS: {RUNS}");
         println!("SIGNIFICANCE LEVEL: {SIGNIFICANCE_LEVEL}");
+        println!("COMPARISONS: {comparisons}");
         println!();
         println!("========== PROPORTION TEST ==========");
-        proportion_of_sequences_passing_a_test(SIGNIFICANCE_LEVEL, &p_vals);
+        proportion_of_sequences_passing_a_test(SIGNIFICANCE_LEVEL, &p_vals, comparisons);
         println!("=====================================");
         println!();
         println!("========== DISTRIBUTION TEST ==========");
-        uniform_distribution_of_p_vals_test(&p_vals);
+        uniform_distribution_of_p_vals_test(&p_vals, comparisons);
         println!("=======================================");
         println!("^^^^^^^^^^ {rng} ^^^^^^^^^^");
         Ok(())
@@ -120,15 +69,44 @@ mod tests {
 
     const SIGNIFICANCE_LEVEL: f64 = 0.01;
 
-    fn proportion_of_sequences_passing_a_test(significance_level: f64, p_vals: &[f64]) {
+    // Probability that at least `failures` of `m` sequences fail a test at `significance_level`.
+    fn binomial_tail(m: usize, significance_level: f64, failures: usize) -> f64 {
+        let mut probability = (1f64 - significance_level).powi(m as i32);
+        let mut tail = 0f64;
+        for i in 0..=m {
+            if i >= failures {
+                tail += probability;
+            }
+            probability *=
+                (m - i) as f64 / (i + 1) as f64 * significance_level / (1f64 - significance_level);
+        }
+        tail
+    }
+
+    fn proportion_of_sequences_passing_a_test(
+        significance_level: f64,
+        p_vals: &[f64],
+        comparisons: usize,
+    ) {
         let p_hat = 1f64 - significance_level;
-        let m = p_vals.len() as f64;
-        let confidence = p_hat - 3f64 * ((p_hat * significance_level) / m).sqrt();
+        let m = p_vals.len();
+        let confidence = p_hat - 3f64 * ((p_hat * significance_level) / m as f64).sqrt();
+        // Bonferroni correction: a single SP800-22 check allows `allowed` failing sequences, and
+        // fails by chance with probability `alpha`. Each comparison may only fail with probability
+        // alpha / comparisons, so the whole test fails by chance no more often than a single check.
+        let allowed = m - (confidence * m as f64).ceil() as usize;
+        let alpha = binomial_tail(m, significance_level, allowed + 1);
+        let allowed = (allowed..m)
+            .find(|&failures| {
+                binomial_tail(m, significance_level, failures + 1) <= alpha / comparisons as f64
+            })
+            .unwrap_or(m);
+        let confidence = (m - allowed) as f64 / m as f64;
         let proportion = p_vals
             .iter()
             .filter(|&&p_val| p_val >= significance_level)
             .count() as f64
-            / m;
+            / m as f64;
         println!(
             "PROPORTION OF SEQUENCES PASSING A TEST WITH SIGNIFICANCE LEVEL {significance_level}:"
         );
@@ -143,7 +121,12 @@ mod tests {
 
     const UNIFORM_DIST_CHUNKS: f64 = 10f64;
 
-    fn uniform_distribution_of_p_vals_test(p_vals: &[f64]) {
+    // SP800-22 Section 4.2.2 considers P-value_T >= 0.0001 uniformly distributed.
+    const UNIFORM_DIST_THRESHOLD: f64 = 0.0001;
+
+    fn uniform_distribution_of_p_vals_test(p_vals: &[f64], comparisons: usize) {
+        // Bonferroni correction, as for the proportion.
+        let threshold = UNIFORM_DIST_THRESHOLD / comparisons as f64;
         let s = p_vals.len() as f64 / UNIFORM_DIST_CHUNKS;
         let mut chi_squared = 0f64;
         for (lower, upper) in (0..).zip(1..=UNIFORM_DIST_CHUNKS as i32) {
@@ -159,13 +142,13 @@ mod tests {
         }
         let p_val_t = igamc(9f64 / 2f64, chi_squared / 2f64);
         println!("UNIFORM DISTRIBUTION OF P-VALUES TEST:");
-        println!("P-VALUE_T {p_val_t} MUST BE >= 0.0001");
-        if p_val_t >= 0.0001 {
+        println!("P-VALUE_T {p_val_t} MUST BE >= {threshold}");
+        if p_val_t >= threshold {
             println!("SUCCESS!");
         } else {
             println!("FAILURE :(");
         }
-        assert!(p_val_t >= 0.0001);
+        assert!(p_val_t >= threshold);
     }
 
     fn monobit(bytes: &[u8]) -> f64 {
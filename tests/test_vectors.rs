@@ -9,15 +9,30 @@ mod tests {
     };
 
     use kondrbg::{
-        DrbgCtrAes128, DrbgCtrAes192, DrbgCtrAes256, DrbgError, DrbgHashSha224, DrbgHashSha256,
-        DrbgHashSha384, DrbgHashSha512, DrbgHashSha512_224, DrbgHashSha512_256, DrbgHmacSha224,
-        DrbgHmacSha256, DrbgHmacSha384, DrbgHmacSha512, DrbgHmacSha512_224, DrbgHmacSha512_256,
-        DrbgPrCtrAes128, DrbgPrCtrAes192, DrbgPrCtrAes256, DrbgPrHashSha224, DrbgPrHashSha256,
-        DrbgPrHashSha384, DrbgPrHashSha512, DrbgPrHashSha512_224, DrbgPrHashSha512_256,
-        DrbgPrHmacSha224, DrbgPrHmacSha256, DrbgPrHmacSha384, DrbgPrHmacSha512,
-        DrbgPrHmacSha512_224, DrbgPrHmacSha512_256, Entropy,
+        Approved, CryptoEntropy, DrbgCtrAes128, DrbgCtrAes192, DrbgCtrAes256, DrbgCtrNoDfAes128,
+        DrbgCtrNoDfAes192, DrbgCtrNoDfAes256, DrbgError, DrbgHashSha3_224, DrbgHashSha3_256,
+        DrbgHashSha3_384, DrbgHashSha3_512, DrbgHashSha224, DrbgHashSha256, DrbgHashSha384,
+        DrbgHashSha512, DrbgHashSha512_224, DrbgHashSha512_256, DrbgHmacSha3_224, DrbgHmacSha3_256,
+        DrbgHmacSha3_384, DrbgHmacSha3_512, DrbgHmacSha224, DrbgHmacSha256, DrbgHmacSha384,
+        DrbgHmacSha512, DrbgHmacSha512_224, DrbgHmacSha512_256, DrbgPrCtrAes128, DrbgPrCtrAes192,
+        DrbgPrCtrAes256, DrbgPrCtrNoDfAes128, DrbgPrCtrNoDfAes192, DrbgPrCtrNoDfAes256,
+        DrbgPrHashSha3_224, DrbgPrHashSha3_256, DrbgPrHashSha3_384, DrbgPrHashSha3_512,
+        DrbgPrHashSha224, DrbgPrHashSha256, DrbgPrHashSha384, DrbgPrHashSha512,
+        DrbgPrHashSha512_224, DrbgPrHashSha512_256, DrbgPrHmacSha3_224, DrbgPrHmacSha3_256,
+        DrbgPrHmacSha3_384, DrbgPrHmacSha3_512, DrbgPrHmacSha224, DrbgPrHmacSha256,
+        DrbgPrHmacSha384, DrbgPrHmacSha512, DrbgPrHmacSha512_224, DrbgPrHmacSha512_256, Entropy,
     };
 
+    #[cfg(all(feature = "camellia", not(feature = "fips")))]
+    use kondrbg::{
+        DrbgCtrCamellia128, DrbgCtrCamellia192, DrbgCtrCamellia256, DrbgCtrNoDfCamellia128,
+        DrbgCtrNoDfCamellia192, DrbgCtrNoDfCamellia256,
+    };
+    #[cfg(all(feature = "legacy-tdea", not(feature = "fips")))]
+    use kondrbg::{DrbgCtrNoDfTdea3, DrbgCtrTdea3, DrbgPrCtrNoDfTdea3, DrbgPrCtrTdea3};
+    #[cfg(all(feature = "legacy-sha1", not(feature = "fips")))]
+    use kondrbg::{DrbgHashSha1, DrbgHmacSha1, DrbgPrHashSha1, DrbgPrHmacSha1};
+
     #[derive(Default)]
     struct MockEntropy {
         bytes: Vec<Vec<u8>>,
@@ -27,6 +42,8 @@ mod tests {
     impl Entropy for MockEntropy {
         type Error = std::convert::Infallible;
 
+        const APPROVED: Option<Approved> = Some(Approved::source::<Self>());
+
         fn fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), Self::Error> {
             let entropy = &self.bytes[self.pos];
             bytes.copy_from_slice(entropy);
@@ -35,6 +52,8 @@ mod tests {
         }
     }
 
+    impl CryptoEntropy for MockEntropy {}
+
     #[derive(Clone, Debug, Default)]
     struct PrTrial {
         entropy_input: String,
@@ -65,14 +84,11 @@ mod tests {
             let line = line.trim();
 
             if line.starts_with('[') && !line.contains('=') {
-                current_name = line
-                    .trim_matches(&['[', ']'][..])
-                    .split_whitespace()
-                    .next()
-                    .unwrap()
-                    .to_string();
-                skip =
-                    line.contains("no df") || line.contains("3KeyTDEA") || line.contains("SHA-1");
+                current_name = line.trim_matches(&['[', ']'][..]).to_string();
+                // The fips feature refuses the legacy mechanisms.
+                let tdea = cfg!(all(feature = "legacy-tdea", not(feature = "fips")));
+                let sha1 = cfg!(all(feature = "legacy-sha1", not(feature = "fips")));
+                skip = (!tdea && line.contains("3KeyTDEA")) || (!sha1 && line.contains("SHA-1"));
                 continue;
             }
 
@@ -126,7 +142,12 @@ mod tests {
 
     #[test]
     fn test_pr_hash() -> Result<(), DrbgError<<MockEntropy as Entropy>::Error>> {
-        for case in generate_pr_test_cases("drbgtestvectors/drbgvectors_pr_true/Hash_DRBG.rsp") {
+        for case in generate_pr_test_cases("drbgtestvectors/drbgvectors_pr_true/Hash_DRBG.rsp")
+            .into_iter()
+            .chain(generate_pr_test_cases(
+                "drbgtestvectors/sha3/drbgvectors_pr_true/Hash_DRBG.rsp",
+            ))
+        {
             for trial in case.trials {
                 println!("{trial:#?}");
                 let returned_bits = hex::decode(&trial.returned_bits).unwrap();
@@ -296,26 +317,10 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    name => unreachable!("Unexpected hash type {name} in PR test vectors."),
-                }
-            }
-        }
-        Ok(())
-    }
-
-    #[test]
-    fn test_pr_hmac() -> Result<(), DrbgError<<MockEntropy as Entropy>::Error>> {
-        for case in generate_pr_test_cases("drbgtestvectors/drbgvectors_pr_true/HMAC_DRBG.rsp") {
-            for trial in case.trials {
-                println!("{trial:#?}");
-                let returned_bits = hex::decode(&trial.returned_bits).unwrap();
-
-                let entropy = fill_pr_entropy(&trial);
-
-                match case.name.as_str() {
-                    "SHA-224" => {
-                        println!("SHA-224");
-                        let mut drbg = DrbgPrHmacSha224::builder()
+                    #[cfg(all(feature = "legacy-sha1", not(feature = "fips")))]
+                    "SHA-1" => {
+                        println!("SHA-1");
+                        let mut drbg = DrbgPrHashSha1::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -340,9 +345,9 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "SHA-256" => {
-                        println!("SHA-256");
-                        let mut drbg = DrbgPrHmacSha256::builder()
+                    "SHA3-224" => {
+                        println!("SHA3-224");
+                        let mut drbg = DrbgPrHashSha3_224::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -367,9 +372,9 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "SHA-384" => {
-                        println!("SHA-384");
-                        let mut drbg = DrbgPrHmacSha384::builder()
+                    "SHA3-256" => {
+                        println!("SHA3-256");
+                        let mut drbg = DrbgPrHashSha3_256::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -394,9 +399,9 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "SHA-512" => {
-                        println!("SHA-512");
-                        let mut drbg = DrbgPrHmacSha512::builder()
+                    "SHA3-384" => {
+                        println!("SHA3-384");
+                        let mut drbg = DrbgPrHashSha3_384::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -421,9 +426,9 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "SHA-512/224" => {
-                        println!("SHA-512/224");
-                        let mut drbg = DrbgPrHmacSha512_224::builder()
+                    "SHA3-512" => {
+                        println!("SHA3-512");
+                        let mut drbg = DrbgPrHashSha3_512::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -448,9 +453,31 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "SHA-512/256" => {
-                        println!("SHA-512/256");
-                        let mut drbg = DrbgPrHmacSha512_256::builder()
+                    name => unreachable!("Unexpected hash type {name} in PR test vectors."),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_pr_hmac() -> Result<(), DrbgError<<MockEntropy as Entropy>::Error>> {
+        for case in generate_pr_test_cases("drbgtestvectors/drbgvectors_pr_true/HMAC_DRBG.rsp")
+            .into_iter()
+            .chain(generate_pr_test_cases(
+                "drbgtestvectors/sha3/drbgvectors_pr_true/HMAC_DRBG.rsp",
+            ))
+        {
+            for trial in case.trials {
+                println!("{trial:#?}");
+                let returned_bits = hex::decode(&trial.returned_bits).unwrap();
+
+                let entropy = fill_pr_entropy(&trial);
+
+                match case.name.as_str() {
+                    "SHA-224" => {
+                        println!("SHA-224");
+                        let mut drbg = DrbgPrHmacSha224::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -475,26 +502,9 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    name => unreachable!("Unexpected hmac type {name} in PR test vectors."),
-                }
-            }
-        }
-        Ok(())
-    }
-
-    #[test]
-    fn test_pr_ctr() -> Result<(), DrbgError<<MockEntropy as Entropy>::Error>> {
-        for case in generate_pr_test_cases("drbgtestvectors/drbgvectors_pr_true/CTR_DRBG.rsp") {
-            for trial in case.trials {
-                println!("{trial:#?}");
-                let returned_bits = hex::decode(&trial.returned_bits).unwrap();
-
-                let entropy = fill_pr_entropy(&trial);
-
-                match case.name.as_str() {
-                    "AES-128" => {
-                        println!("AES-128");
-                        let mut drbg = DrbgPrCtrAes128::builder()
+                    "SHA-256" => {
+                        println!("SHA-256");
+                        let mut drbg = DrbgPrHmacSha256::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -519,9 +529,9 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "AES-192" => {
-                        println!("AES-192");
-                        let mut drbg = DrbgPrCtrAes192::builder()
+                    "SHA-384" => {
+                        println!("SHA-384");
+                        let mut drbg = DrbgPrHmacSha384::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -546,9 +556,9 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "AES-256" => {
-                        println!("AES-256");
-                        let mut drbg = DrbgPrCtrAes256::builder()
+                    "SHA-512" => {
+                        println!("SHA-512");
+                        let mut drbg = DrbgPrHmacSha512::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -573,117 +583,36 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    name => unreachable!("Unexpected cipher type {name} in PR test vectors."),
-                }
-            }
-        }
-        Ok(())
-    }
-
-    #[derive(Clone, Debug, Default)]
-    struct Trial {
-        entropy_input: String,
-        nonce: String,
-        personalization_string: String,
-        entropy_input_reseed: String,
-        additional_input_reseed: String,
-        additional_inputs: Vec<String>,
-        returned_bits: String,
-    }
-
-    #[derive(Default, Debug)]
-    struct TestCase {
-        name: String,
-        trials: Vec<Trial>,
-    }
-
-    fn generate_no_pr_test_cases(path: impl AsRef<Path>) -> Vec<TestCase> {
-        let file = File::open(path).unwrap();
-
-        let mut cases = Vec::new();
-        let mut current_name = String::new();
-        let mut trials = Vec::new();
-        let mut current_trial = Trial::default();
-        let mut skip = false;
-        let mut count = 0;
-
-        for line in BufReader::new(file).lines().map(|l| l.unwrap()) {
-            let line = line.trim();
-
-            if line.starts_with('[') && !line.contains('=') {
-                current_name = line
-                    .trim_matches(&['[', ']'][..])
-                    .split_whitespace()
-                    .next()
-                    .unwrap()
-                    .to_string();
-                skip =
-                    line.contains("no df") || line.contains("3KeyTDEA") || line.contains("SHA-1");
-                continue;
-            }
-
-            if skip {
-                continue;
-            }
+                    "SHA-512/224" => {
+                        println!("SHA-512/224");
+                        let mut drbg = DrbgPrHmacSha512_224::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
 
-            if line.starts_with("COUNT") {
-                count += 1;
-            } else if let Some((k, v)) = line.split_once(" = ") {
-                match k {
-                    "EntropyInput" => current_trial.entropy_input = v.to_string(),
-                    "Nonce" => current_trial.nonce = v.to_string(),
-                    "PersonalizationString" => current_trial.personalization_string = v.to_string(),
-                    "EntropyInputReseed" => current_trial.entropy_input_reseed = v.to_string(),
-                    "AdditionalInputReseed" => {
-                        current_trial.additional_input_reseed = v.to_string()
-                    }
-                    "AdditionalInput" => current_trial.additional_inputs.push(v.to_string()),
-                    "ReturnedBits" => {
-                        current_trial.returned_bits = v.to_string();
-                        trials.push(current_trial.clone());
-                        current_trial = Trial::default();
-                        if count == 15 {
-                            cases.push(TestCase {
-                                name: current_name.clone(),
-                                trials: trials.clone(),
-                            });
-                            trials.clear();
-                            count = 0;
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
                         }
-                    }
-                    _ => {}
-                }
-            }
-        }
-
-        cases
-    }
-
-    fn fill_no_pr_entropy(trial: &Trial) -> MockEntropy {
-        let mut entropy = MockEntropy::default();
-        entropy
-            .bytes
-            .push(hex::decode(&trial.entropy_input).unwrap());
-        entropy
-            .bytes
-            .push(hex::decode(&trial.entropy_input_reseed).unwrap());
-        entropy
-    }
-
-    #[test]
-    fn test_no_pr_hash() -> Result<(), DrbgError<<MockEntropy as Entropy>::Error>> {
-        for case in generate_no_pr_test_cases("drbgtestvectors/drbgvectors_no_reseed/Hash_DRBG.rsp")
-        {
-            for trial in case.trials {
-                println!("{trial:#?}");
-                let returned_bits = hex::decode(&trial.returned_bits).unwrap();
-
-                let entropy = fill_no_pr_entropy(&trial);
 
-                match case.name.as_str() {
-                    "SHA-224" => {
-                        println!("SHA-224");
-                        let mut drbg = DrbgHashSha224::builder()
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA-512/256" => {
+                        println!("SHA-512/256");
+                        let mut drbg = DrbgPrHmacSha512_256::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -708,9 +637,10 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "SHA-256" => {
-                        println!("SHA-256");
-                        let mut drbg = DrbgHashSha256::builder()
+                    #[cfg(all(feature = "legacy-sha1", not(feature = "fips")))]
+                    "SHA-1" => {
+                        println!("SHA-1");
+                        let mut drbg = DrbgPrHmacSha1::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -735,9 +665,9 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "SHA-384" => {
-                        println!("SHA-384");
-                        let mut drbg = DrbgHashSha384::builder()
+                    "SHA3-224" => {
+                        println!("SHA3-224");
+                        let mut drbg = DrbgPrHmacSha3_224::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -762,9 +692,9 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "SHA-512" => {
-                        println!("SHA-512");
-                        let mut drbg = DrbgHashSha512::builder()
+                    "SHA3-256" => {
+                        println!("SHA3-256");
+                        let mut drbg = DrbgPrHmacSha3_256::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -789,9 +719,9 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "SHA-512/224" => {
-                        println!("SHA-512/224");
-                        let mut drbg = DrbgHashSha512_224::builder()
+                    "SHA3-384" => {
+                        println!("SHA3-384");
+                        let mut drbg = DrbgPrHmacSha3_384::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -816,9 +746,9 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "SHA-512/256" => {
-                        println!("SHA-512/256");
-                        let mut drbg = DrbgHashSha512_256::builder()
+                    "SHA3-512" => {
+                        println!("SHA3-512");
+                        let mut drbg = DrbgPrHmacSha3_512::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -843,7 +773,7 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    name => unreachable!("Unexpected hash type {name} in No-PR test vectors."),
+                    name => unreachable!("Unexpected hmac type {name} in PR test vectors."),
                 }
             }
         }
@@ -851,19 +781,18 @@ mod tests {
     }
 
     #[test]
-    fn test_no_pr_hmac() -> Result<(), DrbgError<<MockEntropy as Entropy>::Error>> {
-        for case in generate_no_pr_test_cases("drbgtestvectors/drbgvectors_no_reseed/HMAC_DRBG.rsp")
-        {
+    fn test_pr_ctr() -> Result<(), DrbgError<<MockEntropy as Entropy>::Error>> {
+        for case in generate_pr_test_cases("drbgtestvectors/drbgvectors_pr_true/CTR_DRBG.rsp") {
             for trial in case.trials {
                 println!("{trial:#?}");
                 let returned_bits = hex::decode(&trial.returned_bits).unwrap();
 
-                let entropy = fill_no_pr_entropy(&trial);
+                let entropy = fill_pr_entropy(&trial);
 
                 match case.name.as_str() {
-                    "SHA-224" => {
-                        println!("SHA-224");
-                        let mut drbg = DrbgHmacSha224::builder()
+                    "AES-128 use df" => {
+                        println!("AES-128 use df");
+                        let mut drbg = DrbgPrCtrAes128::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -888,9 +817,9 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "SHA-256" => {
-                        println!("SHA-256");
-                        let mut drbg = DrbgHmacSha256::builder()
+                    "AES-192 use df" => {
+                        println!("AES-192 use df");
+                        let mut drbg = DrbgPrCtrAes192::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -915,9 +844,9 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "SHA-384" => {
-                        println!("SHA-384");
-                        let mut drbg = DrbgHmacSha384::builder()
+                    "AES-256 use df" => {
+                        println!("AES-256 use df");
+                        let mut drbg = DrbgPrCtrAes256::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -942,9 +871,656 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "SHA-512" => {
-                        println!("SHA-512");
-                        let mut drbg = DrbgHmacSha512::builder()
+                    "AES-128 no df" => {
+                        println!("AES-128 no df");
+                        let mut drbg = DrbgPrCtrNoDfAes128::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "AES-192 no df" => {
+                        println!("AES-192 no df");
+                        let mut drbg = DrbgPrCtrNoDfAes192::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "AES-256 no df" => {
+                        println!("AES-256 no df");
+                        let mut drbg = DrbgPrCtrNoDfAes256::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    #[cfg(all(feature = "legacy-tdea", not(feature = "fips")))]
+                    "3KeyTDEA use df" => {
+                        println!("3KeyTDEA use df");
+                        let mut drbg = DrbgPrCtrTdea3::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    #[cfg(all(feature = "legacy-tdea", not(feature = "fips")))]
+                    "3KeyTDEA no df" => {
+                        println!("3KeyTDEA no df");
+                        let mut drbg = DrbgPrCtrNoDfTdea3::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    name => unreachable!("Unexpected cipher type {name} in PR test vectors."),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[derive(Clone, Debug, Default)]
+    struct Trial {
+        entropy_input: String,
+        nonce: String,
+        personalization_string: String,
+        entropy_input_reseed: String,
+        additional_input_reseed: String,
+        additional_inputs: Vec<String>,
+        returned_bits: String,
+    }
+
+    #[derive(Default, Debug)]
+    struct TestCase {
+        name: String,
+        trials: Vec<Trial>,
+    }
+
+    fn generate_no_pr_test_cases(path: impl AsRef<Path>) -> Vec<TestCase> {
+        let file = File::open(path).unwrap();
+
+        let mut cases = Vec::new();
+        let mut current_name = String::new();
+        let mut trials = Vec::new();
+        let mut current_trial = Trial::default();
+        let mut skip = false;
+        let mut count = 0;
+
+        for line in BufReader::new(file).lines().map(|l| l.unwrap()) {
+            let line = line.trim();
+
+            if line.starts_with('[') && !line.contains('=') {
+                current_name = line.trim_matches(&['[', ']'][..]).to_string();
+                // The fips feature refuses the legacy mechanisms.
+                let tdea = cfg!(all(feature = "legacy-tdea", not(feature = "fips")));
+                let sha1 = cfg!(all(feature = "legacy-sha1", not(feature = "fips")));
+                skip = (!tdea && line.contains("3KeyTDEA")) || (!sha1 && line.contains("SHA-1"));
+                continue;
+            }
+
+            if skip {
+                continue;
+            }
+
+            if line.starts_with("COUNT") {
+                count += 1;
+            } else if let Some((k, v)) = line.split_once(" = ") {
+                match k {
+                    "EntropyInput" => current_trial.entropy_input = v.to_string(),
+                    "Nonce" => current_trial.nonce = v.to_string(),
+                    "PersonalizationString" => current_trial.personalization_string = v.to_string(),
+                    "EntropyInputReseed" => current_trial.entropy_input_reseed = v.to_string(),
+                    "AdditionalInputReseed" => {
+                        current_trial.additional_input_reseed = v.to_string()
+                    }
+                    "AdditionalInput" => current_trial.additional_inputs.push(v.to_string()),
+                    "ReturnedBits" => {
+                        current_trial.returned_bits = v.to_string();
+                        trials.push(current_trial.clone());
+                        current_trial = Trial::default();
+                        if count == 15 {
+                            cases.push(TestCase {
+                                name: current_name.clone(),
+                                trials: trials.clone(),
+                            });
+                            trials.clear();
+                            count = 0;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        cases
+    }
+
+    fn fill_no_pr_entropy(trial: &Trial) -> MockEntropy {
+        let mut entropy = MockEntropy::default();
+        entropy
+            .bytes
+            .push(hex::decode(&trial.entropy_input).unwrap());
+        entropy
+            .bytes
+            .push(hex::decode(&trial.entropy_input_reseed).unwrap());
+        entropy
+    }
+
+    #[test]
+    fn test_no_pr_hash() -> Result<(), DrbgError<<MockEntropy as Entropy>::Error>> {
+        for case in generate_no_pr_test_cases("drbgtestvectors/drbgvectors_no_reseed/Hash_DRBG.rsp")
+            .into_iter()
+            .chain(generate_no_pr_test_cases(
+                "drbgtestvectors/sha3/drbgvectors_no_reseed/Hash_DRBG.rsp",
+            ))
+        {
+            for trial in case.trials {
+                println!("{trial:#?}");
+                let returned_bits = hex::decode(&trial.returned_bits).unwrap();
+
+                let entropy = fill_no_pr_entropy(&trial);
+
+                match case.name.as_str() {
+                    "SHA-224" => {
+                        println!("SHA-224");
+                        let mut drbg = DrbgHashSha224::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA-256" => {
+                        println!("SHA-256");
+                        let mut drbg = DrbgHashSha256::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA-384" => {
+                        println!("SHA-384");
+                        let mut drbg = DrbgHashSha384::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA-512" => {
+                        println!("SHA-512");
+                        let mut drbg = DrbgHashSha512::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA-512/224" => {
+                        println!("SHA-512/224");
+                        let mut drbg = DrbgHashSha512_224::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA-512/256" => {
+                        println!("SHA-512/256");
+                        let mut drbg = DrbgHashSha512_256::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    #[cfg(all(feature = "legacy-sha1", not(feature = "fips")))]
+                    "SHA-1" => {
+                        println!("SHA-1");
+                        let mut drbg = DrbgHashSha1::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA3-224" => {
+                        println!("SHA3-224");
+                        let mut drbg = DrbgHashSha3_224::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA3-256" => {
+                        println!("SHA3-256");
+                        let mut drbg = DrbgHashSha3_256::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA3-384" => {
+                        println!("SHA3-384");
+                        let mut drbg = DrbgHashSha3_384::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA3-512" => {
+                        println!("SHA3-512");
+                        let mut drbg = DrbgHashSha3_512::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    name => unreachable!("Unexpected hash type {name} in No-PR test vectors."),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_pr_hmac() -> Result<(), DrbgError<<MockEntropy as Entropy>::Error>> {
+        for case in generate_no_pr_test_cases("drbgtestvectors/drbgvectors_no_reseed/HMAC_DRBG.rsp")
+            .into_iter()
+            .chain(generate_no_pr_test_cases(
+                "drbgtestvectors/sha3/drbgvectors_no_reseed/HMAC_DRBG.rsp",
+            ))
+        {
+            for trial in case.trials {
+                println!("{trial:#?}");
+                let returned_bits = hex::decode(&trial.returned_bits).unwrap();
+
+                let entropy = fill_no_pr_entropy(&trial);
+
+                match case.name.as_str() {
+                    "SHA-224" => {
+                        println!("SHA-224");
+                        let mut drbg = DrbgHmacSha224::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA-256" => {
+                        println!("SHA-256");
+                        let mut drbg = DrbgHmacSha256::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA-384" => {
+                        println!("SHA-384");
+                        let mut drbg = DrbgHmacSha384::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA-512" => {
+                        println!("SHA-512");
+                        let mut drbg = DrbgHmacSha512::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -996,9 +1572,145 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "SHA-512/256" => {
-                        println!("SHA-512/256");
-                        let mut drbg = DrbgHmacSha512_256::builder()
+                    "SHA-512/256" => {
+                        println!("SHA-512/256");
+                        let mut drbg = DrbgHmacSha512_256::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    #[cfg(all(feature = "legacy-sha1", not(feature = "fips")))]
+                    "SHA-1" => {
+                        println!("SHA-1");
+                        let mut drbg = DrbgHmacSha1::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA3-224" => {
+                        println!("SHA3-224");
+                        let mut drbg = DrbgHmacSha3_224::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA3-256" => {
+                        println!("SHA3-256");
+                        let mut drbg = DrbgHmacSha3_256::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA3-384" => {
+                        println!("SHA3-384");
+                        let mut drbg = DrbgHmacSha3_384::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "SHA3-512" => {
+                        println!("SHA3-512");
+                        let mut drbg = DrbgHmacSha3_512::builder()
                             .entropy(entropy)
                             .personalization_string(
                                 &hex::decode(trial.personalization_string).unwrap(),
@@ -1041,8 +1753,8 @@ mod tests {
                 let entropy = fill_no_pr_entropy(&trial);
 
                 match case.name.as_str() {
-                    "AES-128" => {
-                        println!("AES-128");
+                    "AES-128 use df" => {
+                        println!("AES-128 use df");
                         let mut drbg = DrbgCtrAes128::builder()
                             .entropy(entropy)
                             .personalization_string(
@@ -1068,8 +1780,8 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "AES-192" => {
-                        println!("AES-192");
+                    "AES-192 use df" => {
+                        println!("AES-192 use df");
                         let mut drbg = DrbgCtrAes192::builder()
                             .entropy(entropy)
                             .personalization_string(
@@ -1095,8 +1807,8 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
-                    "AES-256" => {
-                        println!("AES-256");
+                    "AES-256 use df" => {
+                        println!("AES-256 use df");
                         let mut drbg = DrbgCtrAes256::builder()
                             .entropy(entropy)
                             .personalization_string(
@@ -1122,10 +1834,493 @@ mod tests {
 
                         assert!(bytes == returned_bits);
                     }
+                    "AES-128 no df" => {
+                        println!("AES-128 no df");
+                        let mut drbg = DrbgCtrNoDfAes128::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "AES-192 no df" => {
+                        println!("AES-192 no df");
+                        let mut drbg = DrbgCtrNoDfAes192::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    "AES-256 no df" => {
+                        println!("AES-256 no df");
+                        let mut drbg = DrbgCtrNoDfAes256::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    #[cfg(all(feature = "legacy-tdea", not(feature = "fips")))]
+                    "3KeyTDEA use df" => {
+                        println!("3KeyTDEA use df");
+                        let mut drbg = DrbgCtrTdea3::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
+                    #[cfg(all(feature = "legacy-tdea", not(feature = "fips")))]
+                    "3KeyTDEA no df" => {
+                        println!("3KeyTDEA no df");
+                        let mut drbg = DrbgCtrNoDfTdea3::builder()
+                            .entropy(entropy)
+                            .personalization_string(
+                                &hex::decode(trial.personalization_string).unwrap(),
+                            )
+                            .nonce(&hex::decode(trial.nonce).unwrap())
+                            .build()?;
+
+                        let mut bytes = vec![0; returned_bits.len()];
+                        if trial.additional_inputs.is_empty() {
+                            drbg.fill_bytes(&mut bytes)?;
+                            drbg.fill_bytes(&mut bytes)?;
+                        } else {
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                            )?;
+                            drbg.fill_bytes_with_ai(
+                                &mut bytes,
+                                &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                            )?;
+                        }
+
+                        assert!(bytes == returned_bits);
+                    }
                     name => unreachable!("Unexpected cipher type {name} in No-PR test vectors."),
                 }
             }
         }
         Ok(())
     }
+
+    // CAVP does not cover ctr_len < blocklen. These answers come from a reference implementation
+    // that reproduces every AES CTR_DRBG vector above, with seeds chosen so that the counter wraps.
+    #[test]
+    fn test_ctr_len() -> Result<(), DrbgError<<MockEntropy as Entropy>::Error>> {
+        let trial = Trial {
+            entropy_input: "58be64d846aab64f027ab18259413dc4".to_string(),
+            nonce: "9256c58860068592".to_string(),
+            personalization_string: "c9adb2647e813bf47e2599b0d98550e2".to_string(),
+            additional_inputs: vec![
+                "60d6bc516f5fc90461cf304cd0474b17".to_string(),
+                "cc0bf905a4c58cbfc0968e8fda20e82a".to_string(),
+            ],
+            returned_bits: "5f6649c0ad5e3cba18af2297c74082f238caa252ab5d782dc3f17a6c0465a2d2\
+                            e5bad2dde860da2c073a06d12d42a29647b4880406567451a293c37585aacedc"
+                .to_string(),
+            ..Default::default()
+        };
+        let returned_bits = hex::decode(&trial.returned_bits).unwrap();
+        let mut drbg = DrbgCtrAes128::builder()
+            .entropy(fill_no_pr_entropy(&trial))
+            .personalization_string(&hex::decode(&trial.personalization_string).unwrap())
+            .nonce(&hex::decode(&trial.nonce).unwrap())
+            .ctr_len(4)
+            .build()?;
+        let mut bytes = vec![0; returned_bits.len()];
+        drbg.fill_bytes_with_ai(
+            &mut bytes,
+            &hex::decode(&trial.additional_inputs[0]).unwrap(),
+        )?;
+        drbg.fill_bytes_with_ai(
+            &mut bytes,
+            &hex::decode(&trial.additional_inputs[1]).unwrap(),
+        )?;
+        assert!(bytes == returned_bits);
+
+        let trial = Trial {
+            entropy_input: "7594e086083e48aaf6a3aa3420c35946e1be70d10787e5bc\
+                            210fa4339eb575d9f60d2dca9a501d40f75325c883aed585"
+                .to_string(),
+            personalization_string:
+                "e2f91868e00cd7d250bbc1553abc274f29854fb2d34a76c8fd28e496357d6e84".to_string(),
+            additional_inputs: vec![
+                "102b95349bcb950216779c4d1c30d73d76741978faa3f1df549fed7448d888e9".to_string(),
+                "92fdca3f11f509ce269b622fcbf1b9721bd7bb6a3096046b7a84e83de73870c8".to_string(),
+            ],
+            returned_bits: "7365ed9dfe3a8e0d769c9349a32440f39678a94ce25ffef7cefb952e88348cf0\
+                            fc0fb97b837bab545b59f16d16a22ae95a7f9db1048e84cc13dac279fbc758ec"
+                .to_string(),
+            ..Default::default()
+        };
+        let returned_bits = hex::decode(&trial.returned_bits).unwrap();
+        let mut drbg = DrbgCtrNoDfAes256::builder()
+            .entropy(fill_no_pr_entropy(&trial))
+            .personalization_string(&hex::decode(&trial.personalization_string).unwrap())
+            .ctr_len(8)
+            .build()?;
+        let mut bytes = vec![0; returned_bits.len()];
+        drbg.fill_bytes_with_ai(
+            &mut bytes,
+            &hex::decode(&trial.additional_inputs[0]).unwrap(),
+        )?;
+        drbg.fill_bytes_with_ai(
+            &mut bytes,
+            &hex::decode(&trial.additional_inputs[1]).unwrap(),
+        )?;
+        assert!(bytes == returned_bits);
+
+        for ctr_len in [3, 129] {
+            let result = DrbgCtrAes128::builder()
+                .entropy(MockEntropy::default())
+                .ctr_len(ctr_len)
+                .build();
+            assert!(matches!(result, Err(DrbgError::InvalidParameters)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_entropy_input_len() -> Result<(), DrbgError<<MockEntropy as Entropy>::Error>> {
+        // SHA-256 Hash_DRBG with 384 bit entropy inputs on instantiate and both reseeds.
+        let trial = PrTrial {
+            entropy_input: "24e123d7538cf7a23c8f8e6ed94b7e0f8edaf7aa4a109575\
+                            83373c341fd4d1e3bbda089f5e9afb83b382ff2dc2fdeb9b"
+                .to_string(),
+            nonce: "b19698e3b6c338ee13082951975ca366".to_string(),
+            personalization_string:
+                "6e0ee049ec109a0d7bd07e5cb10b059e2f11c99467cc9b48a114f352d099e01e".to_string(),
+            additional_inputs: vec![
+                "2883d8b0aa11be7d083fe801e24f4c20e9e4c189c5307c83018a9aef08144184".to_string(),
+                "1b63d8d97d71f27023faa98730c7dcfa51f66564a2e8d60cd99d317269a439d0".to_string(),
+            ],
+            entropy_input_prs: vec![
+                "69dae8704579995c42c80a693d20b50a726677e38dd1a281\
+                 fab258d0f0a6f30f7452ce245d850d878725c0ab4796f08a"
+                    .to_string(),
+                "809786ae1067b32a73f5f43d69a515addc79bbbdd4c96e1c\
+                 61442e47ad01420877d4dbf05dff0b9e62f458d6b4a7c166"
+                    .to_string(),
+            ],
+            returned_bits: "0a355f3b615b9d81135f18ca139b0314c3ad4972944e7c4080c95dc3a34804c9\
+                            bc7425bd189852a989b5657c889eb50a375638631d667d4377de291beb3ce298"
+                .to_string(),
+        };
+        let returned_bits = hex::decode(&trial.returned_bits).unwrap();
+        let mut drbg = DrbgPrHashSha256::builder()
+            .entropy(fill_pr_entropy(&trial))
+            .personalization_string(&hex::decode(&trial.personalization_string).unwrap())
+            .nonce(&hex::decode(&trial.nonce).unwrap())
+            .entropy_input_len(48)
+            .build()?;
+        let mut bytes = vec![0; returned_bits.len()];
+        drbg.fill_bytes_with_ai(
+            &mut bytes,
+            &hex::decode(&trial.additional_inputs[0]).unwrap(),
+        )?;
+        drbg.fill_bytes_with_ai(
+            &mut bytes,
+            &hex::decode(&trial.additional_inputs[1]).unwrap(),
+        )?;
+        assert!(bytes == returned_bits);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reseed() -> Result<(), DrbgError<<MockEntropy as Entropy>::Error>> {
+        // SHA-256 HMAC_DRBG explicitly reseeded between instantiate and generate.
+        let trial = Trial {
+            entropy_input: "601a234867b4f0695dedb1a6440817cef2ba73431adc1b2e819305e953889885"
+                .to_string(),
+            nonce: "265ef57a98ff7e250efdb72e71856b1e".to_string(),
+            personalization_string:
+                "bcd7f6c065aa9c72d706671d9c164495ee6030f446a607da46cc6393fea2e067".to_string(),
+            entropy_input_reseed:
+                "dc5edb257526cb2c42935ececcab417e9ee7228759e4607da198e4b50454fac1".to_string(),
+            additional_input_reseed:
+                "5f1ce4d5729accc6eeb475cbc02e327c56c379e189ea3882a0a4defeeb41230c".to_string(),
+            additional_inputs: vec![
+                "582dee7bb7cee72f508d802d1ec6da07700752a418075907bb063b0b21a79188".to_string(),
+                "8c0185e727632afee5286da5cf54334c448f6f4bc93b45f900eafa77718e971a".to_string(),
+            ],
+            returned_bits: "f6302fcb91dad7bbba8625aed08a208dce35b118081c6eb1c74129471ddbf8a1\
+                            60b2ef315e3def0f7ee701e861ea30a975d00e962da166adfca5ba62100c3009\
+                            06d1ccf66b0515d3b855a629c0448d7ddd18e085dac834d4fee465112094a15c\
+                            801702a00bf649a95a03cba9cc7fc80cacb5e5372f4bb7e88c9538d705b5f9c6"
+                .to_string(),
+        };
+        let returned_bits = hex::decode(&trial.returned_bits).unwrap();
+        let mut drbg = DrbgHmacSha256::builder()
+            .entropy(fill_no_pr_entropy(&trial))
+            .personalization_string(&hex::decode(&trial.personalization_string).unwrap())
+            .nonce(&hex::decode(&trial.nonce).unwrap())
+            .build()?;
+        drbg.reseed_with_ai(&hex::decode(&trial.additional_input_reseed).unwrap())?;
+        let mut bytes = vec![0; returned_bits.len()];
+        drbg.fill_bytes_with_ai(
+            &mut bytes,
+            &hex::decode(&trial.additional_inputs[0]).unwrap(),
+        )?;
+        drbg.fill_bytes_with_ai(
+            &mut bytes,
+            &hex::decode(&trial.additional_inputs[1]).unwrap(),
+        )?;
+        assert!(bytes == returned_bits);
+
+        // Without df additional input is limited to seedlen, checked before the entropy source is read.
+        let mut entropy = MockEntropy::default();
+        entropy.bytes.push(vec![0; 32]);
+        let mut drbg = DrbgCtrNoDfAes128::builder().entropy(entropy).build()?;
+        assert!(matches!(
+            drbg.reseed_with_ai(&[0; 33]),
+            Err(DrbgError::AdditionalInputTooLong)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_bytes_pr() -> Result<(), DrbgError<<MockEntropy as Entropy>::Error>> {
+        // Per-request prediction resistance on a NoPr DRBG must replay the PR vectors.
+        for case in generate_pr_test_cases("drbgtestvectors/drbgvectors_pr_true/Hash_DRBG.rsp") {
+            if case.name != "SHA-256" {
+                continue;
+            }
+            for trial in case.trials {
+                let returned_bits = hex::decode(&trial.returned_bits).unwrap();
+                let mut drbg = DrbgHashSha256::builder()
+                    .entropy(fill_pr_entropy(&trial))
+                    .personalization_string(&hex::decode(&trial.personalization_string).unwrap())
+                    .nonce(&hex::decode(&trial.nonce).unwrap())
+                    .build()?;
+
+                let mut bytes = vec![0; returned_bits.len()];
+                if trial.additional_inputs.is_empty() {
+                    drbg.fill_bytes_pr(&mut bytes, &[])?;
+                    drbg.fill_bytes_pr(&mut bytes, &[])?;
+                } else {
+                    drbg.fill_bytes_pr(
+                        &mut bytes,
+                        &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                    )?;
+                    drbg.fill_bytes_pr(
+                        &mut bytes,
+                        &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                    )?;
+                }
+
+                assert!(bytes == returned_bits);
+            }
+        }
+
+        for case in generate_pr_test_cases("drbgtestvectors/drbgvectors_pr_true/CTR_DRBG.rsp") {
+            if case.name != "AES-128 use df" {
+                continue;
+            }
+            for trial in case.trials {
+                let returned_bits = hex::decode(&trial.returned_bits).unwrap();
+                let mut drbg = DrbgCtrAes128::builder()
+                    .entropy(fill_pr_entropy(&trial))
+                    .personalization_string(&hex::decode(&trial.personalization_string).unwrap())
+                    .nonce(&hex::decode(&trial.nonce).unwrap())
+                    .build()?;
+
+                let mut bytes = vec![0; returned_bits.len()];
+                if trial.additional_inputs.is_empty() {
+                    drbg.fill_bytes_pr(&mut bytes, &[])?;
+                    drbg.fill_bytes_pr(&mut bytes, &[])?;
+                } else {
+                    drbg.fill_bytes_pr(
+                        &mut bytes,
+                        &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                    )?;
+                    drbg.fill_bytes_pr(
+                        &mut bytes,
+                        &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                    )?;
+                }
+
+                assert!(bytes == returned_bits);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_uninstantiate() -> Result<(), DrbgError<<MockEntropy as Entropy>::Error>> {
+        // The entropy source handed back by uninstantiate keeps its position for the next instance.
+        let case = generate_no_pr_test_cases("drbgtestvectors/drbgvectors_no_reseed/HMAC_DRBG.rsp")
+            .into_iter()
+            .find(|case| case.name == "SHA-256")
+            .unwrap();
+        let mut entropy = MockEntropy::default();
+        for trial in &case.trials {
+            entropy
+                .bytes
+                .push(hex::decode(&trial.entropy_input).unwrap());
+        }
+
+        for trial in case.trials {
+            let returned_bits = hex::decode(&trial.returned_bits).unwrap();
+            let mut drbg = DrbgHmacSha256::builder()
+                .entropy(entropy)
+                .personalization_string(&hex::decode(&trial.personalization_string).unwrap())
+                .nonce(&hex::decode(&trial.nonce).unwrap())
+                .build()?;
+
+            let mut bytes = vec![0; returned_bits.len()];
+            if trial.additional_inputs.is_empty() {
+                drbg.fill_bytes(&mut bytes)?;
+                drbg.fill_bytes(&mut bytes)?;
+            } else {
+                drbg.fill_bytes_with_ai(
+                    &mut bytes,
+                    &hex::decode(&trial.additional_inputs[0]).unwrap(),
+                )?;
+                drbg.fill_bytes_with_ai(
+                    &mut bytes,
+                    &hex::decode(&trial.additional_inputs[1]).unwrap(),
+                )?;
+            }
+
+            assert!(bytes == returned_bits);
+            entropy = drbg.uninstantiate();
+        }
+        assert_eq!(entropy.pos, entropy.bytes.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_self_test() {
+        // The embedded known answers must agree with the implementations tested above.
+        macro_rules! assert_self_test {
+            ($($name:ident),*$(,)?) => {
+                $(
+                    let mut drbg = $name::builder().self_test(true).build().unwrap();
+                    assert!(drbg.self_test().is_ok());
+                    assert!(!drbg.is_in_error_state());
+                )*
+            };
+        }
+
+        assert_self_test!(
+            DrbgCtrAes128,
+            DrbgCtrAes192,
+            DrbgCtrAes256,
+            DrbgCtrNoDfAes128,
+            DrbgCtrNoDfAes192,
+            DrbgCtrNoDfAes256,
+            DrbgHashSha224,
+            DrbgHashSha512_224,
+            DrbgHashSha256,
+            DrbgHashSha512_256,
+            DrbgHashSha384,
+            DrbgHashSha512,
+            DrbgHashSha3_224,
+            DrbgHashSha3_256,
+            DrbgHashSha3_384,
+            DrbgHashSha3_512,
+            DrbgHmacSha224,
+            DrbgHmacSha512_224,
+            DrbgHmacSha256,
+            DrbgHmacSha512_256,
+            DrbgHmacSha384,
+            DrbgHmacSha512,
+            DrbgHmacSha3_224,
+            DrbgHmacSha3_256,
+            DrbgHmacSha3_384,
+            DrbgHmacSha3_512,
+            DrbgPrCtrAes256,
+            DrbgPrHashSha256,
+            DrbgPrHmacSha256,
+        );
+        #[cfg(all(feature = "legacy-sha1", not(feature = "fips")))]
+        assert_self_test!(DrbgHashSha1, DrbgHmacSha1);
+        #[cfg(all(feature = "legacy-tdea", not(feature = "fips")))]
+        assert_self_test!(DrbgCtrTdea3, DrbgCtrNoDfTdea3);
+        // Camellia is not approved, the fips feature refuses it as well.
+        #[cfg(all(feature = "camellia", not(feature = "fips")))]
+        assert_self_test!(
+            DrbgCtrCamellia128,
+            DrbgCtrCamellia192,
+            DrbgCtrCamellia256,
+            DrbgCtrNoDfCamellia128,
+            DrbgCtrNoDfCamellia192,
+            DrbgCtrNoDfCamellia256,
+        );
+    }
 }